@@ -0,0 +1,40 @@
+//! This module is a home for centrality measures that rank vertices of a graph by their importance.
+//! All measures return a score for every vertex of a graph keyed by its index.
+//!
+//! Path-based measures follow the adjacency of the vertex topology, so in directed graphs paths
+//! go along outgoing edges. They take an optional `weight` function of two adjacent vertices, which
+//! is treated as the length of the edge between them and must be positive. Without it, every
+//! edge has unit length; [`unweighted`](crate::utils::unweighted) spells this out.
+//!
+//! Spectral measures like PageRank are computed iteratively and report whether they have converged.
+//! They are primarily meant for directed graphs, where scores flow along the outgoing edges.
+
+use crate::index::Index;
+use crate::utils::Dense;
+
+mod betweenness;
+mod geometric;
+mod spectral;
+
+pub use betweenness::*;
+pub use geometric::*;
+pub use spectral::*;
+
+/// Compute lengths of links of a dense graph with the optional `weight` function.
+///
+/// # Panics
+///
+/// Panics if any length is not positive, since zero-length edges make some vertices infinitely
+/// close to each other.
+fn lengths<I, W>(dense: &Dense<I>, weight: Option<W>) -> Option<Vec<Vec<f64>>>
+where
+    I: Index,
+    W: Fn(I, I) -> f64,
+{
+    let lengths = dense.weights(weight?);
+    assert!(
+        lengths.iter().flatten().all(|length| *length > 0.0),
+        "edge lengths must be positive"
+    );
+    Some(lengths)
+}
@@ -0,0 +1,127 @@
+use super::lengths;
+use crate::index::Index;
+use crate::providers::{EdgeProvider, VertexProvider};
+use crate::topology::Topology;
+use crate::utils::{Dense, ShortestPaths};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Scores of graph edges, hashed the same way as the edge topology of the graph.
+pub type EdgeScores<'a, I, G> = HashMap<
+    <G as EdgeProvider<I>>::Edge,
+    f64,
+    <<G as EdgeProvider<I>>::Edges<'a> as Topology>::BuildHasher,
+>;
+
+/// Compute the betweenness centrality with Brandes' algorithm, i.e. for each vertex, the sum over
+/// all pairs of other vertices of the fraction of shortest paths between them that pass through
+/// it. The sum runs over ordered pairs, so in undirected graphs every pair contributes twice. If
+/// `normalized` is set, scores are divided by the number of ordered pairs `(n - 1) * (n - 2)`.
+/// Edge lengths are given by the optional `weight` function, and are all equal to one without it.
+/// The algorithm runs in `O(V * E)` time for unweighted graphs.
+///
+/// # Panics
+///
+/// Panics if the `weight` of any edge is not positive.
+pub fn betweenness<I, G, W>(graph: &G, weight: Option<W>, normalized: bool) -> HashMap<I, f64>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = lengths(&dense, weight);
+    vertex_betweenness(&dense, weights.as_deref(), normalized)
+}
+
+/// Compute the edge betweenness centrality, i.e. for each edge, the sum over all ordered pairs of
+/// vertices of the fraction of shortest paths between them that pass through the edge. Every edge
+/// of the graph is present in the result. If `normalized` is set, scores are divided by the number
+/// of ordered pairs `n * (n - 1)`. Edge lengths are given by the optional `weight` function.
+///
+/// # Panics
+///
+/// Panics if the `weight` of any edge is not positive.
+pub fn edge_betweenness<I, G, W>(
+    graph: &G,
+    weight: Option<W>,
+    normalized: bool,
+) -> EdgeScores<'_, I, G>
+where
+    I: Index,
+    G: VertexProvider<I> + EdgeProvider<I>,
+    G::Edge: From<(I, I)> + Eq + Hash,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = lengths(&dense, weight);
+    let mut scores = graph
+        .edges()
+        .iter()
+        .map(|edge| (edge, 0.0))
+        .collect::<EdgeScores<'_, I, G>>();
+    let contribute = |v: usize, w: usize, credit: f64| {
+        let edge = G::Edge::from((dense.ids[v], dense.ids[w]));
+        if let Some(score) = scores.get_mut(&edge) {
+            *score += credit;
+        }
+    };
+    accumulate(&dense, weights.as_deref(), contribute, |_, _| ());
+
+    let n = dense.len();
+    if normalized && n > 1 {
+        let scale = 1.0 / (n * (n - 1)) as f64;
+        scores.values_mut().for_each(|score| *score *= scale);
+    }
+    scores
+}
+
+fn vertex_betweenness<I: Index>(
+    dense: &Dense<I>,
+    weights: Option<&[Vec<f64>]>,
+    normalized: bool,
+) -> HashMap<I, f64> {
+    let n = dense.len();
+    let mut scores = vec![0.0; n];
+    accumulate(dense, weights, |_, _, _| (), |w, delta| scores[w] += delta);
+
+    let scale = match n {
+        n if normalized && n > 2 => 1.0 / ((n - 1) * (n - 2)) as f64,
+        _ => 1.0,
+    };
+    dense
+        .ids
+        .iter()
+        .zip(scores)
+        .map(|(id, score)| (*id, score * scale))
+        .collect()
+}
+
+/// Run the dependency accumulation of Brandes' algorithm from every source. For each link `v -> w`
+/// on a shortest path DAG, `on_link` receives the credit that flows through it, and for each vertex
+/// `w` except the source, `on_vertex` receives its total dependency.
+fn accumulate<I>(
+    dense: &Dense<I>,
+    weights: Option<&[Vec<f64>]>,
+    mut on_link: impl FnMut(usize, usize, f64),
+    mut on_vertex: impl FnMut(usize, f64),
+) {
+    let mut dependency = vec![0.0; dense.len()];
+    for source in 0..dense.len() {
+        let paths = ShortestPaths::new(dense, weights, source);
+        for v in &paths.order {
+            dependency[*v] = 0.0;
+        }
+        for &w in paths.order.iter().rev() {
+            for &v in &paths.predecessors[w] {
+                let credit = paths.paths[v] / paths.paths[w] * (1.0 + dependency[w]);
+                on_link(v, w, credit);
+                dependency[v] += credit;
+            }
+            if w != source {
+                on_vertex(w, dependency[w]);
+            }
+        }
+    }
+}
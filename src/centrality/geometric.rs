@@ -0,0 +1,125 @@
+use super::lengths;
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::{Dense, ShortestPaths};
+
+use std::collections::HashMap;
+
+/// Compute the degree centrality, i.e. the number of vertices adjacent to each vertex. For directed
+/// graphs this is the out-degree. With a `weight` function, this is the weighted degree, also known
+/// as strength, i.e. the sum of weights of edges leaving each vertex. If `normalized` is set,
+/// scores are divided by the maximum possible degree `n - 1`.
+pub fn degree<I, G, W>(graph: &G, weight: Option<W>, normalized: bool) -> HashMap<I, f64>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let scores = match weight {
+        Some(weight) => dense
+            .weights(weight)
+            .iter()
+            .map(|w| w.iter().sum())
+            .collect(),
+        None => dense
+            .adjacency
+            .iter()
+            .map(|adjacent| adjacent.len() as f64)
+            .collect::<Vec<_>>(),
+    };
+    collect(&dense, scores.into_iter(), normalized)
+}
+
+/// Compute the closeness centrality, i.e. the inverse of the average distance from each vertex
+/// to all vertices reachable from it. Vertices that reach nothing have zero closeness. If
+/// `normalized` is set, scores are additionally scaled by the fraction of reachable vertices
+/// as proposed by Wasserman and Faust, which makes scores comparable in disconnected graphs.
+/// Edge lengths are given by the optional `weight` function, and are all equal to one without it.
+///
+/// # Panics
+///
+/// Panics if the `weight` of any edge is not positive.
+pub fn closeness<I, G, W>(graph: &G, weight: Option<W>, normalized: bool) -> HashMap<I, f64>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = lengths(&dense, weight);
+    closeness_of(&dense, weights.as_deref(), normalized)
+}
+
+/// Compute the harmonic centrality, i.e. the sum of inverse distances from each vertex to all
+/// other vertices. Unreachable vertices contribute nothing, so unlike closeness, this measure
+/// is well-defined for disconnected graphs. If `normalized` is set, scores are divided by `n - 1`.
+/// Edge lengths are given by the optional `weight` function, and are all equal to one without it.
+///
+/// # Panics
+///
+/// Panics if the `weight` of any edge is not positive.
+pub fn harmonic<I, G, W>(graph: &G, weight: Option<W>, normalized: bool) -> HashMap<I, f64>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = lengths(&dense, weight);
+    harmonic_of(&dense, weights.as_deref(), normalized)
+}
+
+fn closeness_of<I: Index>(
+    dense: &Dense<I>,
+    weights: Option<&[Vec<f64>]>,
+    normalized: bool,
+) -> HashMap<I, f64> {
+    let n = dense.len();
+    let scores = (0..n).map(|source| {
+        let paths = ShortestPaths::new(dense, weights, source);
+        let reached = paths.order.len() - 1;
+        let total = paths.order.iter().map(|v| paths.distance[*v]).sum::<f64>();
+        match reached {
+            0 => 0.0,
+            _ if normalized => (reached * reached) as f64 / total / (n - 1) as f64,
+            _ => reached as f64 / total,
+        }
+    });
+    collect(dense, scores, false)
+}
+
+fn harmonic_of<I: Index>(
+    dense: &Dense<I>,
+    weights: Option<&[Vec<f64>]>,
+    normalized: bool,
+) -> HashMap<I, f64> {
+    let scores = (0..dense.len()).map(|source| {
+        let paths = ShortestPaths::new(dense, weights, source);
+        paths
+            .order
+            .iter()
+            .skip(1)
+            .map(|v| 1.0 / paths.distance[*v])
+            .sum()
+    });
+    collect(dense, scores, normalized)
+}
+
+/// Key the `scores` by vertex indices, dividing them by `n - 1` if `normalized` is set.
+fn collect<I: Index>(
+    dense: &Dense<I>,
+    scores: impl Iterator<Item = f64>,
+    normalized: bool,
+) -> HashMap<I, f64> {
+    let scale = match dense.len() {
+        n if normalized && n > 1 => 1.0 / (n - 1) as f64,
+        _ => 1.0,
+    };
+    dense
+        .ids
+        .iter()
+        .zip(scores)
+        .map(|(id, score)| (*id, score * scale))
+        .collect()
+}
@@ -0,0 +1,211 @@
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::HashMap;
+
+/// Scores produced by an iterative ranking algorithm along with the convergence information.
+#[derive(Clone, Debug)]
+pub struct Ranking<I> {
+    /// Scores of the vertices.
+    pub scores: HashMap<I, f64>,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// Whether the scores have converged within the given tolerance.
+    pub converged: bool,
+}
+
+/// Hub and authority scores produced by [`hits`].
+#[derive(Clone, Debug)]
+pub struct Hits<I> {
+    /// Hub scores, high for vertices that link to many good authorities.
+    pub hubs: HashMap<I, f64>,
+    /// Authority scores, high for vertices linked by many good hubs.
+    pub authorities: HashMap<I, f64>,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// Whether the scores have converged within the given tolerance.
+    pub converged: bool,
+}
+
+/// Compute PageRank of the vertices with power iteration. At each step a random surfer follows
+/// an outgoing edge with probability `damping` and teleports to a uniformly random vertex
+/// otherwise. Dangling vertices without outgoing edges teleport unconditionally. The iteration
+/// stops when the total absolute change of scores drops below `tolerance`, or after `max_iter`
+/// iterations. Scores sum up to one.
+pub fn pagerank<I, G>(graph: &G, damping: f64, tolerance: f64, max_iter: usize) -> Ranking<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let n = dense.len();
+    let teleport = vec![1.0 / n as f64; n];
+    pagerank_of(&dense, &teleport, damping, tolerance, max_iter)
+}
+
+/// Compute personalized PageRank, where the random surfer teleports to vertices with probabilities
+/// proportional to the `personalization` weights instead of uniformly. Vertices missing from
+/// the `personalization` have zero weight, and if all weights are zero, teleportation is
+/// uniform. See [`pagerank`] for the meaning of other parameters.
+pub fn personalized_pagerank<I, G>(
+    graph: &G,
+    damping: f64,
+    tolerance: f64,
+    max_iter: usize,
+    personalization: &HashMap<I, f64>,
+) -> Ranking<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let n = dense.len();
+    let mut teleport = dense
+        .ids
+        .iter()
+        .map(|id| personalization.get(id).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    let total = teleport.iter().sum::<f64>();
+    match total > 0.0 {
+        true => teleport.iter_mut().for_each(|p| *p /= total),
+        false => teleport.iter_mut().for_each(|p| *p = 1.0 / n as f64),
+    }
+    pagerank_of(&dense, &teleport, damping, tolerance, max_iter)
+}
+
+/// Compute hub and authority scores with Kleinberg's HITS algorithm. Authority of a vertex is
+/// the sum of hub scores of vertices linking to it, and hub score is the sum of authorities it
+/// links to. Both score vectors are normalized to sum up to one after every iteration, and the
+/// iteration stops when the total absolute change of hub scores drops below `tolerance`, or
+/// after `max_iter` iterations.
+pub fn hits<I, G>(graph: &G, tolerance: f64, max_iter: usize) -> Hits<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let n = dense.len();
+    let mut hubs = vec![1.0 / n as f64; n];
+    let mut authorities = vec![0.0; n];
+    let (iterations, converged) = iterate(max_iter, |iteration| {
+        if iteration > 0 {
+            authorities.iter_mut().for_each(|a| *a = 0.0);
+        }
+        for (v, adjacent) in dense.adjacency.iter().enumerate() {
+            for &u in adjacent {
+                authorities[u] += hubs[v];
+            }
+        }
+        normalize(&mut authorities);
+        let previous = std::mem::take(&mut hubs);
+        hubs = dense
+            .adjacency
+            .iter()
+            .map(|adjacent| adjacent.iter().map(|u| authorities[*u]).sum())
+            .collect();
+        normalize(&mut hubs);
+        difference(&hubs, &previous) < tolerance
+    });
+    Hits {
+        hubs: collect(&dense, hubs),
+        authorities: collect(&dense, authorities),
+        iterations,
+        converged,
+    }
+}
+
+/// Compute Katz centrality, i.e. the solution of `x = alpha * A^T x + beta`, where `A` is the
+/// adjacency matrix. Each vertex receives the base score `beta` plus the scores of vertices linking
+/// to it attenuated by `alpha`, so walks of length `k` contribute with weight `alpha^k`. The
+/// iteration converges only if `alpha` is smaller than the inverse of the largest eigenvalue
+/// of the adjacency matrix, and stops when the total absolute change of scores drops below
+/// `tolerance`, or after `max_iter` iterations.
+pub fn katz<I, G>(graph: &G, alpha: f64, beta: f64, tolerance: f64, max_iter: usize) -> Ranking<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let mut scores = vec![0.0; dense.len()];
+    let (iterations, converged) = iterate(max_iter, |_| {
+        let mut next = vec![beta; dense.len()];
+        for (v, adjacent) in dense.adjacency.iter().enumerate() {
+            for &u in adjacent {
+                next[u] += alpha * scores[v];
+            }
+        }
+        let change = difference(&next, &scores);
+        scores = next;
+        change < tolerance
+    });
+    Ranking {
+        scores: collect(&dense, scores),
+        iterations,
+        converged,
+    }
+}
+
+fn pagerank_of<I: Index>(
+    dense: &Dense<I>,
+    teleport: &[f64],
+    damping: f64,
+    tolerance: f64,
+    max_iter: usize,
+) -> Ranking<I> {
+    let n = dense.len();
+    let mut scores = vec![1.0 / n as f64; n];
+    let (iterations, converged) = iterate(max_iter, |_| {
+        let dangling = dense
+            .adjacency
+            .iter()
+            .zip(&scores)
+            .filter(|(adjacent, _)| adjacent.is_empty())
+            .map(|(_, score)| score)
+            .sum::<f64>();
+        let mut next = teleport
+            .iter()
+            .map(|p| (damping * dangling + 1.0 - damping) * p)
+            .collect::<Vec<_>>();
+        for (v, adjacent) in dense.adjacency.iter().enumerate() {
+            let share = damping * scores[v] / adjacent.len() as f64;
+            for &u in adjacent {
+                next[u] += share;
+            }
+        }
+        let change = difference(&next, &scores);
+        scores = next;
+        change < tolerance
+    });
+    Ranking {
+        scores: collect(dense, scores),
+        iterations,
+        converged,
+    }
+}
+
+/// Run `step` until it reports convergence or `max_iter` is reached. Returns the number of
+/// iterations performed and whether the iteration has converged.
+fn iterate(max_iter: usize, mut step: impl FnMut(usize) -> bool) -> (usize, bool) {
+    for iteration in 0..max_iter {
+        if step(iteration) {
+            return (iteration + 1, true);
+        }
+    }
+    (max_iter, false)
+}
+
+fn difference(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).abs()).sum()
+}
+
+fn normalize(scores: &mut [f64]) {
+    let total = scores.iter().sum::<f64>();
+    if total > 0.0 {
+        scores.iter_mut().for_each(|score| *score /= total);
+    }
+}
+
+fn collect<I: Index>(dense: &Dense<I>, scores: Vec<f64>) -> HashMap<I, f64> {
+    dense.ids.iter().copied().zip(scores).collect()
+}
@@ -0,0 +1,200 @@
+//! This module is a home for clique algorithms. A clique is a set of items where every two items
+//! are adjacent, and a maximal clique is a clique that cannot be extended with another item.
+//! The adjacency relation is expected to be symmetric, so the algorithms are meant for
+//! undirected graphs.
+
+use crate::index::Index;
+use crate::topology::Topology;
+use crate::utils::Dense;
+
+use std::collections::HashSet;
+
+/// Lazily enumerate all maximal cliques of a `topology` using the Bron-Kerbosch algorithm with
+/// Tomita pivoting. The outer level of the search processes items in a degeneracy ordering,
+/// which bounds the size of candidate sets by the degeneracy of the graph and makes the algorithm
+/// efficient on sparse graphs. Every maximal clique is reported exactly once, isolated items
+/// forming cliques of their own. The topology is read once when this function is called, so the
+/// iterator does not borrow it.
+pub fn maximal_cliques<T: Topology>(topology: &T) -> impl Iterator<Item = Vec<T::Item>>
+where
+    T::Item: Index,
+{
+    let dense = Dense::new(topology);
+    let mut search = Search::new(&dense);
+    std::iter::from_fn(move || {
+        search
+            .next_clique()
+            .map(|clique| clique.into_iter().map(|v| dense.ids[v]).collect())
+    })
+}
+
+/// Find a maximum clique, i.e. the largest of the maximal cliques of a `topology`. Returns an
+/// empty vector for an empty topology.
+pub fn max_clique<T: Topology>(topology: &T) -> Vec<T::Item>
+where
+    T::Item: Index,
+{
+    maximal_cliques(topology)
+        .max_by_key(|clique| clique.len())
+        .unwrap_or_default()
+}
+
+/// A level of the Bron-Kerbosch recursion.
+struct Frame {
+    clique: Vec<usize>,
+    candidates: Vec<usize>,
+    excluded: Vec<usize>,
+    branches: Vec<usize>,
+}
+
+/// Bron-Kerbosch search with an explicit stack, so it can be suspended after each clique.
+struct Search {
+    neighbors: Vec<HashSet<usize>>,
+    order: Vec<usize>,
+    rank: Vec<usize>,
+    next: usize,
+    stack: Vec<Frame>,
+}
+
+impl Search {
+    fn new<I>(dense: &Dense<I>) -> Self {
+        let neighbors = dense
+            .adjacency
+            .iter()
+            .map(|adjacent| adjacent.iter().copied().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+        let order = degeneracy_ordering(&neighbors);
+        let mut rank = vec![0; order.len()];
+        for (position, v) in order.iter().enumerate() {
+            rank[*v] = position;
+        }
+        Self {
+            neighbors,
+            order,
+            rank,
+            next: 0,
+            stack: Vec::new(),
+        }
+    }
+
+    fn next_clique(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let frame = match self.stack.last_mut() {
+                Some(frame) => frame,
+                None => {
+                    let v = *self.order.get(self.next)?;
+                    self.next += 1;
+                    let (later, earlier) = self.neighbors[v]
+                        .iter()
+                        .partition(|u| self.rank[**u] > self.rank[v]);
+                    if let Some(clique) = self.descend(vec![v], later, earlier) {
+                        return Some(clique);
+                    }
+                    continue;
+                }
+            };
+            let v = match frame.branches.pop() {
+                Some(v) => v,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+
+            let adjacent = &self.neighbors[v];
+            let mut clique = frame.clique.clone();
+            clique.push(v);
+            let candidates = frame
+                .candidates
+                .iter()
+                .copied()
+                .filter(|u| adjacent.contains(u))
+                .collect();
+            let excluded = frame
+                .excluded
+                .iter()
+                .copied()
+                .filter(|u| adjacent.contains(u))
+                .collect();
+            frame.candidates.retain(|u| *u != v);
+            frame.excluded.push(v);
+            if let Some(clique) = self.descend(clique, candidates, excluded) {
+                return Some(clique);
+            }
+        }
+    }
+
+    /// Either report the `clique` if it is maximal, or push a new frame to extend it.
+    fn descend(
+        &mut self,
+        clique: Vec<usize>,
+        candidates: Vec<usize>,
+        excluded: Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        if candidates.is_empty() {
+            return excluded.is_empty().then_some(clique);
+        }
+
+        // Tomita pivot: the item covering the most candidates, so that as few branches as
+        // possible are explored.
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .max_by_key(|u| {
+                let adjacent = &self.neighbors[**u];
+                candidates.iter().filter(|w| adjacent.contains(w)).count()
+            })
+            .copied()
+            .unwrap();
+        let adjacent = &self.neighbors[pivot];
+        let branches = candidates
+            .iter()
+            .copied()
+            .filter(|u| !adjacent.contains(u))
+            .collect();
+        self.stack.push(Frame {
+            clique,
+            candidates,
+            excluded,
+            branches,
+        });
+        None
+    }
+}
+
+/// Order items by repeatedly removing an item with the smallest remaining degree.
+fn degeneracy_ordering(neighbors: &[HashSet<usize>]) -> Vec<usize> {
+    let n = neighbors.len();
+    let mut degree = neighbors.iter().map(HashSet::len).collect::<Vec<_>>();
+    let max_degree = degree.iter().copied().max().unwrap_or_default();
+    let mut buckets = vec![Vec::new(); max_degree + 1];
+    for (v, d) in degree.iter().enumerate() {
+        buckets[*d].push(v);
+    }
+
+    // Buckets keep stale entries for items whose degree has decreased since, so entries are
+    // validated against the current degree when popped.
+    let mut removed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut lowest = 0;
+    while order.len() < n {
+        let v = match buckets[lowest].pop() {
+            Some(v) if !removed[v] && degree[v] == lowest => v,
+            Some(_) => continue,
+            None => {
+                lowest += 1;
+                continue;
+            }
+        };
+        removed[v] = true;
+        order.push(v);
+        for &u in &neighbors[v] {
+            if !removed[u] {
+                degree[u] -= 1;
+                buckets[degree[u]].push(u);
+                lowest = lowest.min(degree[u]);
+            }
+        }
+    }
+    order
+}
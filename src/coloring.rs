@@ -0,0 +1,244 @@
+//! This module is a home for vertex coloring algorithms. A coloring assigns a color, represented
+//! by a small integer, to every item of a topology so that no two adjacent items share the same
+//! color. Colors are numbered consecutively starting from zero. The adjacency relation is
+//! expected to be symmetric, so the algorithms are meant for undirected graphs.
+
+use crate::index::Index;
+use crate::topology::Topology;
+use crate::utils::Dense;
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+const NONE: usize = usize::MAX;
+
+/// Color assignment produced by coloring algorithms.
+#[derive(Clone, Debug)]
+pub struct Coloring<I> {
+    /// Colors of the items.
+    pub colors: HashMap<I, usize>,
+    /// The number of different colors used.
+    pub count: usize,
+}
+
+impl<I: Index> Coloring<I> {
+    fn from_dense(dense: &Dense<I>, colors: &[usize]) -> Self {
+        let count = colors
+            .iter()
+            .filter(|color| **color != NONE)
+            .map(|color| color + 1)
+            .max()
+            .unwrap_or_default();
+        let colors = dense
+            .ids
+            .iter()
+            .zip(colors)
+            .filter(|(_, color)| **color != NONE)
+            .map(|(id, color)| (*id, *color))
+            .collect();
+        Self { colors, count }
+    }
+}
+
+/// Color items one by one in the given `order`, assigning each the smallest color not used by
+/// its already colored neighbors. The quality of the result depends heavily on the order.
+/// Items that are not enumerated in the `order` are left uncolored.
+pub fn greedy<T: Topology>(
+    topology: &T,
+    order: impl IntoIterator<Item = T::Item>,
+) -> Coloring<T::Item>
+where
+    T::Item: Index,
+{
+    let dense = Dense::new(topology);
+    let order = order
+        .into_iter()
+        .filter_map(|id| dense.positions.get(&id).copied());
+    let colors = color_greedily(&dense, order);
+    Coloring::from_dense(&dense, &colors)
+}
+
+/// Color items greedily in the order of decreasing degree, as proposed by Welsh and Powell.
+/// If `d_i` is the degree of the `i`-th item in this order, counting from one, the number of
+/// colors used is at most `max_i min(d_i + 1, i)`.
+pub fn welsh_powell<T: Topology>(topology: &T) -> Coloring<T::Item>
+where
+    T::Item: Index,
+{
+    let dense = Dense::new(topology);
+    let mut order = (0..dense.len()).collect::<Vec<_>>();
+    order.sort_by_key(|v| std::cmp::Reverse(dense.adjacency[*v].len()));
+    let colors = color_greedily(&dense, order);
+    Coloring::from_dense(&dense, &colors)
+}
+
+/// Color items with the DSatur heuristic by Brélaz. At each step it colors the item with the
+/// largest number of distinct colors among its neighbors (saturation), breaking ties by degree.
+/// DSatur is exact for bipartite graphs, cycles and wheels, and usually outperforms
+/// static orderings.
+pub fn dsatur<T: Topology>(topology: &T) -> Coloring<T::Item>
+where
+    T::Item: Index,
+{
+    let dense = Dense::new(topology);
+    let n = dense.len();
+    let mut colors = vec![NONE; n];
+    let mut neighbor_colors = vec![HashSet::new(); n];
+    let mut queue = (0..n)
+        .map(|v| (0, dense.adjacency[v].len(), v))
+        .collect::<BinaryHeap<_>>();
+
+    // The queue holds stale entries for items whose saturation has grown since they were
+    // pushed, so items that are already colored are skipped.
+    while let Some((_, _, v)) = queue.pop() {
+        if colors[v] != NONE {
+            continue;
+        }
+        let color = smallest_free_color(&dense, &colors, v);
+        colors[v] = color;
+        for &neighbor in &dense.adjacency[v] {
+            if colors[neighbor] == NONE && neighbor_colors[neighbor].insert(color) {
+                let saturation = neighbor_colors[neighbor].len();
+                let degree = dense.adjacency[neighbor].len();
+                queue.push((saturation, degree, neighbor));
+            }
+        }
+    }
+    Coloring::from_dense(&dense, &colors)
+}
+
+/// Find a coloring with the minimum possible number of colors using backtracking search with
+/// DSatur branching. The search starts from the [`dsatur`] solution and stops as soon as it
+/// matches the size of a greedily found clique. The chromatic number problem is NP-hard, so the
+/// search is limited by the `budget` of visited search nodes, and `None` is returned if the budget
+/// runs out before the optimality of the best coloring is proven.
+pub fn exact<T: Topology>(topology: &T, budget: usize) -> Option<Coloring<T::Item>>
+where
+    T::Item: Index,
+{
+    let dense = Dense::new(topology);
+    let initial = dsatur(topology);
+    let mut best = dense
+        .ids
+        .iter()
+        .map(|id| initial.colors[id])
+        .collect::<Vec<_>>();
+
+    let lower_bound = greedy_clique_size(&dense);
+    let mut search = Search {
+        dense: &dense,
+        colors: vec![NONE; dense.len()],
+        best_count: initial.count,
+        best: &mut best,
+        steps: 0,
+        budget,
+    };
+    if initial.count > lower_bound && !search.run(0, 0, lower_bound) {
+        return None;
+    }
+    Some(Coloring::from_dense(&dense, &best))
+}
+
+fn color_greedily<I>(dense: &Dense<I>, order: impl IntoIterator<Item = usize>) -> Vec<usize> {
+    let mut colors = vec![NONE; dense.len()];
+    for v in order {
+        if colors[v] == NONE {
+            colors[v] = smallest_free_color(dense, &colors, v);
+        }
+    }
+    colors
+}
+
+fn smallest_free_color<I>(dense: &Dense<I>, colors: &[usize], v: usize) -> usize {
+    let used = dense.adjacency[v]
+        .iter()
+        .map(|neighbor| colors[*neighbor])
+        .filter(|color| *color != NONE)
+        .collect::<HashSet<_>>();
+    (0..).find(|color| !used.contains(color)).unwrap()
+}
+
+/// Find the size of a clique built greedily from the item with the largest degree. Every coloring
+/// needs at least that many colors.
+fn greedy_clique_size<I>(dense: &Dense<I>) -> usize {
+    let start = (0..dense.len()).max_by_key(|v| dense.adjacency[*v].len());
+    let start = match start {
+        Some(start) => start,
+        None => return 0,
+    };
+    let mut candidates = dense.adjacency[start].clone();
+    candidates.sort_by_key(|v| std::cmp::Reverse(dense.adjacency[*v].len()));
+    let mut clique = vec![start];
+    for candidate in candidates {
+        if clique
+            .iter()
+            .all(|v| dense.adjacency[candidate].contains(v))
+        {
+            clique.push(candidate);
+        }
+    }
+    clique.len()
+}
+
+/// State of the exact coloring search.
+struct Search<'a, I> {
+    dense: &'a Dense<I>,
+    colors: Vec<usize>,
+    best: &'a mut Vec<usize>,
+    best_count: usize,
+    steps: usize,
+    budget: usize,
+}
+
+impl<'a, I> Search<'a, I> {
+    /// Extend the partial coloring with `colored` items and `used` colors. Returns `false` if
+    /// the budget is exhausted.
+    fn run(&mut self, colored: usize, used: usize, lower_bound: usize) -> bool {
+        self.steps += 1;
+        if self.steps > self.budget {
+            return false;
+        }
+        if colored == self.dense.len() {
+            self.best.copy_from_slice(&self.colors);
+            self.best_count = used;
+            return true;
+        }
+
+        let v = self.most_saturated();
+        let mut forbidden = vec![false; self.best_count];
+        for &neighbor in &self.dense.adjacency[v] {
+            if let Some(flag) = forbidden.get_mut(self.colors[neighbor]) {
+                *flag = true;
+            }
+        }
+        for (color, taken) in forbidden.into_iter().enumerate().take(used + 1) {
+            if self.best_count <= lower_bound {
+                break;
+            }
+            if taken || color + 1 >= self.best_count {
+                continue;
+            }
+            self.colors[v] = color;
+            let complete = self.run(colored + 1, used.max(color + 1), lower_bound);
+            self.colors[v] = NONE;
+            if !complete {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn most_saturated(&self) -> usize {
+        let saturation = |v: usize| {
+            self.dense.adjacency[v]
+                .iter()
+                .map(|neighbor| self.colors[*neighbor])
+                .filter(|color| *color != NONE)
+                .collect::<HashSet<_>>()
+                .len()
+        };
+        (0..self.dense.len())
+            .filter(|v| self.colors[*v] == NONE)
+            .max_by_key(|v| (saturation(*v), self.dense.adjacency[*v].len()))
+            .unwrap()
+    }
+}
@@ -0,0 +1,107 @@
+//! This module is a home for community detection algorithms that partition vertices of a graph
+//! into densely connected groups. A partition maps each vertex to the [`CommunityId`] of its
+//! community, and communities are numbered consecutively starting from zero.
+//!
+//! The algorithms are meant for undirected graphs. They take an optional `weight` function of
+//! two adjacent vertices, which must be symmetric and non-negative. Without it, every edge has
+//! unit weight; [`unweighted`](crate::utils::unweighted) spells this out.
+//!
+//! Label propagation makes random choices, so it is only available with the `rand` feature.
+
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::HashMap;
+
+#[cfg(feature = "rand")]
+mod label_propagation;
+mod louvain;
+
+#[cfg(feature = "rand")]
+pub use label_propagation::*;
+pub use louvain::*;
+
+/// Identifier of a community in a partition.
+pub type CommunityId = usize;
+
+/// Compute the modularity of a `partition`, i.e. the fraction of edges that fall within
+/// communities minus the expected fraction if edges were distributed at random with the same
+/// degrees. Edge weights are given by the optional `weight` function. Vertices missing from the
+/// `partition` are treated as singleton communities. Returns zero for graphs without edges.
+pub fn modularity<I, G, W>(graph: &G, weight: Option<W>, partition: &HashMap<I, CommunityId>) -> f64
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = weights_of(&dense, weight);
+    modularity_of(&dense, &weights, partition, 1.0)
+}
+
+/// Return weights of links of a dense graph, which are all equal to one without a `weight`.
+fn weights_of<I, W>(dense: &Dense<I>, weight: Option<W>) -> Vec<Vec<f64>>
+where
+    I: Index,
+    W: Fn(I, I) -> f64,
+{
+    match weight {
+        Some(weight) => dense.weights(weight),
+        None => dense.weights(|_, _| 1.0),
+    }
+}
+
+/// Compute modularity of a `partition` with the given `resolution`.
+fn modularity_of<I: Index>(
+    dense: &Dense<I>,
+    weights: &[Vec<f64>],
+    partition: &HashMap<I, CommunityId>,
+    resolution: f64,
+) -> f64 {
+    let mut numbers = HashMap::new();
+    let communities = dense
+        .ids
+        .iter()
+        .enumerate()
+        .map(|(v, id)| {
+            let key = partition.get(id).copied().ok_or(v);
+            let count = numbers.len();
+            *numbers.entry(key).or_insert(count)
+        })
+        .collect::<Vec<_>>();
+
+    let mut internal = vec![0.0; numbers.len()];
+    let mut total = vec![0.0; numbers.len()];
+    let mut volume = 0.0;
+    for (v, adjacent) in dense.adjacency.iter().enumerate() {
+        for (u, weight) in adjacent.iter().zip(&weights[v]) {
+            if communities[*u] == communities[v] {
+                internal[communities[v]] += weight;
+            }
+            total[communities[v]] += weight;
+            volume += weight;
+        }
+    }
+    if volume == 0.0 {
+        return 0.0;
+    }
+    internal
+        .iter()
+        .zip(&total)
+        .map(|(internal, total)| internal / volume - resolution * (total / volume).powi(2))
+        .sum()
+}
+
+/// Renumber community labels so that they are consecutive, in the order of their first
+/// appearance, and key them by vertex indices.
+fn relabel<I: Index>(ids: &[I], labels: &[usize]) -> HashMap<I, CommunityId> {
+    let mut numbers = HashMap::new();
+    ids.iter()
+        .zip(labels)
+        .map(|(id, label)| {
+            let count = numbers.len();
+            (*id, *numbers.entry(*label).or_insert(count))
+        })
+        .collect()
+}
@@ -0,0 +1,76 @@
+use super::{relabel, weights_of, CommunityId};
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Detect communities with asynchronous label propagation. Every vertex starts with a label of
+/// its own, and then vertices repeatedly adopt the label that is most frequent among their
+/// neighbors, visiting vertices in random order and breaking ties randomly. The process stops
+/// when every vertex has one of the most frequent labels of its neighborhood. Random tie-breaking
+/// may keep labels changing forever on some graphs, so it also stops after `max_iter` passes over
+/// all vertices, returning the labels it has reached. Neighbor labels are weighted by the optional
+/// `weight` of the edges leading to them. Random choices are drawn from `rng`, and since vertices
+/// are ordered by their indices beforehand, seeding the generator makes the result reproducible.
+pub fn label_propagation<I, G, W, R>(
+    graph: &G,
+    weight: Option<W>,
+    max_iter: usize,
+    rng: &mut R,
+) -> HashMap<I, CommunityId>
+where
+    I: Index + Ord,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+    R: Rng,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = weights_of(&dense, weight);
+    let mut order = (0..dense.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|v| dense.ids[*v]);
+    let mut labels = vec![0; dense.len()];
+    for (label, v) in order.iter().enumerate() {
+        labels[*v] = label;
+    }
+
+    for _ in 0..max_iter {
+        let mut stable = true;
+        order.shuffle(rng);
+        for &v in &order {
+            let candidates = most_frequent(&dense.adjacency[v], &weights[v], &labels);
+            if candidates.is_empty() || candidates.contains(&labels[v]) {
+                continue;
+            }
+            labels[v] = *candidates.choose(rng).unwrap();
+            stable = false;
+        }
+        if stable {
+            break;
+        }
+    }
+
+    order.sort_unstable_by_key(|v| dense.ids[*v]);
+    let ids = order.iter().map(|v| dense.ids[*v]).collect::<Vec<_>>();
+    let labels = order.iter().map(|v| labels[*v]).collect::<Vec<_>>();
+    relabel(&ids, &labels)
+}
+
+/// Return the labels with the largest total weight among the `adjacent` vertices, in ascending
+/// order.
+fn most_frequent(adjacent: &[usize], weights: &[f64], labels: &[usize]) -> Vec<usize> {
+    let mut frequencies = HashMap::new();
+    for (u, weight) in adjacent.iter().zip(weights) {
+        *frequencies.entry(labels[*u]).or_insert(0.0) += weight;
+    }
+    let best = frequencies.values().copied().fold(f64::MIN, f64::max);
+    let mut candidates = frequencies
+        .into_iter()
+        .filter(|(_, frequency)| *frequency == best)
+        .map(|(label, _)| label)
+        .collect::<Vec<_>>();
+    candidates.sort_unstable();
+    candidates
+}
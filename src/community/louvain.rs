@@ -0,0 +1,134 @@
+use super::{relabel, weights_of, CommunityId};
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::HashMap;
+
+/// Minimal modularity gain that is considered an improvement, to avoid moving vertices back
+/// and forth because of rounding errors.
+const EPSILON: f64 = 1e-12;
+
+/// Detect communities by greedy modularity optimization with the Louvain method. Each vertex is
+/// first moved to the neighboring community that improves modularity the most, and once no move
+/// helps, communities are merged into single vertices and the process repeats on the resulting
+/// graph. Edge weights are given by the optional `weight` function. The `resolution` controls
+/// the size of communities: values above one favor smaller communities, and values below one
+/// favor larger ones. The result can be scored with [`modularity`](super::modularity).
+pub fn louvain<I, G, W>(graph: &G, weight: Option<W>, resolution: f64) -> HashMap<I, CommunityId>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = weights_of(&dense, weight);
+    let mut level = Level {
+        links: dense
+            .adjacency
+            .iter()
+            .zip(&weights)
+            .map(|(adjacent, weights)| adjacent.iter().copied().zip(weights.iter().copied()))
+            .map(Iterator::collect)
+            .collect(),
+    };
+
+    // Community of every original vertex, updated after each aggregation.
+    let mut membership = (0..dense.len()).collect::<Vec<_>>();
+    loop {
+        let (communities, moved) = level.optimize(resolution);
+        if !moved {
+            break;
+        }
+        membership.iter_mut().for_each(|c| *c = communities[*c]);
+        level = level.aggregate(&communities);
+    }
+
+    relabel(&dense.ids, &membership)
+}
+
+/// A level of the Louvain hierarchy: a weighted graph whose vertices are communities of the
+/// previous level. Links are symmetric, and self-loops hold the weight inside a community,
+/// counted once for each direction.
+struct Level {
+    links: Vec<Vec<(usize, f64)>>,
+}
+
+impl Level {
+    /// Move vertices between communities while modularity improves. Returns consecutive
+    /// community numbers for all vertices, and whether any vertex has been moved.
+    fn optimize(&self, resolution: f64) -> (Vec<usize>, bool) {
+        let n = self.links.len();
+        let strength = self
+            .links
+            .iter()
+            .map(|links| links.iter().map(|(_, weight)| weight).sum::<f64>())
+            .collect::<Vec<_>>();
+        let volume = strength.iter().sum::<f64>();
+        let mut community = (0..n).collect::<Vec<_>>();
+        let mut total = strength.clone();
+        if volume == 0.0 {
+            return (community, false);
+        }
+
+        let mut moved = false;
+        let mut improved = true;
+        let mut shared = HashMap::new();
+        while improved {
+            improved = false;
+            for v in 0..n {
+                // Weights of links from `v` to each neighboring community, excluding self-loops.
+                shared.clear();
+                for &(u, weight) in &self.links[v] {
+                    if u != v {
+                        *shared.entry(community[u]).or_insert(0.0) += weight;
+                    }
+                }
+
+                let current = community[v];
+                total[current] -= strength[v];
+                let gain =
+                    |c: usize, shared: f64| shared - resolution * total[c] * strength[v] / volume;
+                let mut best = current;
+                let mut best_gain = gain(current, shared.get(&current).copied().unwrap_or(0.0));
+                for (&c, &weight) in &shared {
+                    let candidate = gain(c, weight);
+                    if candidate > best_gain + EPSILON || candidate == best_gain && c < best {
+                        best = c;
+                        best_gain = candidate;
+                    }
+                }
+                total[best] += strength[v];
+                if best != current {
+                    community[v] = best;
+                    improved = true;
+                    moved = true;
+                }
+            }
+        }
+
+        let mut numbers = HashMap::new();
+        for c in community.iter_mut() {
+            let count = numbers.len();
+            *c = *numbers.entry(*c).or_insert(count);
+        }
+        (community, moved)
+    }
+
+    /// Collapse every community into a single vertex.
+    fn aggregate(&self, communities: &[usize]) -> Level {
+        let n = communities.iter().map(|c| c + 1).max().unwrap_or_default();
+        let mut links = vec![HashMap::new(); n];
+        for (v, adjacent) in self.links.iter().enumerate() {
+            for &(u, weight) in adjacent {
+                *links[communities[v]].entry(communities[u]).or_insert(0.0) += weight;
+            }
+        }
+        Level {
+            links: links
+                .into_iter()
+                .map(|links| links.into_iter().collect())
+                .collect(),
+        }
+    }
+}
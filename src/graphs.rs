@@ -5,5 +5,13 @@
 pub mod adjacency_list;
 pub use adjacency_list::AdjacencyList;
 
+mod edge;
+
 pub mod grid;
-pub use grid::Grid;
+pub use grid::{DenseGrid, Grid};
+
+pub mod hex_grid;
+pub use hex_grid::HexGrid;
+
+pub mod lattice;
+pub use lattice::Lattice;
@@ -94,10 +94,9 @@ impl<I: Index> Construct<I> for AdjacencyList<I, Undirected> {
 
 // Vertex and edge providers
 
-impl<I: Index, D> VertexProvider<I> for AdjacencyList<I, D> {
+impl<I: Index> VertexProvider<I> for AdjacencyList<I, Directed> {
     type Vertices<'a>
     where
-        D: 'a,
         I: 'a,
     = impl Topology<Item = I>;
 
@@ -108,6 +107,54 @@ impl<I: Index, D> VertexProvider<I> for AdjacencyList<I, D> {
     fn vertices(&self) -> Self::Vertices<'_> {
         Vertices { graph: self }
     }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        Some(self.out_degree(item)? + self.in_degree(item)?)
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.storage.get(&item).map(HashSet::len)
+    }
+
+    /// Only outgoing edges are stored, so this scans adjacency of all vertices in `O(V + E)`.
+    fn in_degree(&self, item: I) -> Option<usize> {
+        if !self.storage.contains_key(&item) {
+            return None;
+        }
+        let count = self
+            .storage
+            .values()
+            .filter(|links| links.contains(&item))
+            .count();
+        Some(count)
+    }
+}
+
+impl<I: Index> VertexProvider<I> for AdjacencyList<I, Undirected> {
+    type Vertices<'a>
+    where
+        I: 'a,
+    = impl Topology<Item = I>;
+
+    fn order(&self) -> usize {
+        self.storage.len()
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        Vertices { graph: self }
+    }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        self.storage.get(&item).map(HashSet::len)
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
 }
 
 impl<I: Index> EdgeProvider<I> for AdjacencyList<I, Directed> {
@@ -0,0 +1,35 @@
+//! Undirected edges shared by grid-like graphs, which are re-exported by their modules.
+
+use crate::utils::{UnorderedBuildHasher, UnorderedPair};
+
+use std::collections::HashSet;
+
+/// An undirected edge between two vertices, equal to the edge with its ends swapped.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Edge<I>(pub(crate) UnorderedPair<I>);
+
+impl<I> Edge<I> {
+    pub fn new(a: I, b: I) -> Self {
+        Self(UnorderedPair(a, b))
+    }
+}
+
+impl<I> From<(I, I)> for Edge<I> {
+    fn from(tuple: (I, I)) -> Edge<I> {
+        Edge::new(tuple.0, tuple.1)
+    }
+}
+
+/// Use `EdgeSet` whenever you need to store a hash set of edges of a grid-like graph.
+pub type EdgeSet<I> = HashSet<Edge<I>, UnorderedBuildHasher>;
+
+/// Return edges from `source` to its `neighbors`, except for the edge leading to `exclude`.
+pub(super) fn outbound_edges<I: PartialEq + Copy>(
+    source: I,
+    exclude: I,
+    neighbors: impl Iterator<Item = I>,
+) -> impl Iterator<Item = Edge<I>> {
+    neighbors
+        .filter(move |target| *target != exclude)
+        .map(move |target| Edge::new(source, target))
+}
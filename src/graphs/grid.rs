@@ -1,45 +1,58 @@
 //! This module defines a 2D grid graph and its related strcutres.
 
+use super::edge::outbound_edges;
+use crate::construct::Construct;
 use crate::index::*;
 use crate::providers::*;
 use crate::topology::*;
-use crate::utils::{Bifunctor, Collapse};
 use crate::utils::{UnorderedBuildHasher, UnorderedPair};
 
-use itertools::Itertools;
 use std::collections::hash_map::RandomState;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::ops::RangeBounds;
 
-/// Coordinates of a vertex in a grid in row-column order.
-#[derive(Copy, Clone, Debug)]
-pub struct Coords(pub usize, pub usize);
+mod dense;
+mod layout;
+#[cfg(feature = "rand")]
+pub mod maze;
+mod neighborhood;
+mod orders;
+mod region;
+mod visibility;
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
-pub struct Edge<I>(UnorderedPair<I>);
+pub use super::edge::{Edge, EdgeSet};
+pub use dense::DenseGrid;
+pub use neighborhood::*;
+pub use region::Region;
 
-impl<I> Edge<I> {
-    pub fn new(a: I, b: I) -> Self {
-        Self(UnorderedPair(a, b))
-    }
-}
+use layout::Layout;
 
-/// Use `EdgeSet` whenever you need to store a hash set of `Grid`'s edges.
-pub type EdgeSet<I> = HashSet<Edge<I>, UnorderedBuildHasher>;
+/// Coordinates of a vertex in a grid in row-column order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Coords(pub usize, pub usize);
 
 /// A 2D grid graph, arranging it's nodes in a rectangular grid. It does not provide any
 /// vertex or edge-related storage for weights. Every vertex is connected to all its
 /// neighbors by a bidirectional edge, where neighborhood is defined as having the same
 /// row or column. In other words, any vertex will have 2, 3, or 4 neighbors at most.
+/// Other neighborhoods, like the 8-way one, can be chosen with [`Grid::with_neighborhood`],
+/// and borders can be glued together with [`Grid::with_wrapping`].
 ///
 /// Edges in this graph are not stored directly and thus don't take up memory. Edge topology,
 /// and correspondingly, traversals are available, and can be used to associate edges
 /// with weights.
+///
+/// Obstacles are supported through the [`Construct`] trait. Removing a vertex blocks its cell,
+/// and adding it back restores the cell. Unlinking two neighbors puts a wall between them, and
+/// linking them removes the wall. Only walls are stored, so the grid stays cheap.
 pub struct Grid<I = Counter> {
-    rows: usize,
-    columns: usize,
-    grid: Vec<Vec<I>>,
+    layout: Layout,
+    grid: Vec<Vec<Option<I>>>,
     coords: HashMap<I, Coords>,
+    removed: HashMap<I, Coords>,
+    walls: EdgeSet<I>,
+    corner_cutting: bool,
 }
 
 /// Construction interface.
@@ -64,25 +77,53 @@ impl<I: Unique + Index> Grid<I> {
             for c in 0..columns {
                 let id = Unique::generate();
                 coords.insert(id, Coords(r, c));
-                row.push(id);
+                row.push(Some(id));
                 inspector(id, r, c);
             }
             grid.push(row)
         }
         Self {
-            rows,
-            columns,
+            layout: Layout::new(rows, columns),
             grid,
             coords,
+            removed: HashMap::new(),
+            walls: EdgeSet::default(),
+            corner_cutting: true,
         }
     }
 }
 
+/// Configuration interface.
+impl<I> Grid<I> {
+    /// Replace the neighborhood that defines which cells are linked by edges.
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.layout.offsets = neighborhood.offsets();
+        self
+    }
+
+    /// Allow or forbid diagonal steps between cells that share a corner when any of the two cells
+    /// sharing sides with both of them is removed. Corner cutting is allowed by default.
+    pub fn with_corner_cutting(mut self, allowed: bool) -> Self {
+        self.corner_cutting = allowed;
+        self
+    }
+
+    /// Glue the borders of the grid together. See [`Wrapping`] for the handling of narrow grids.
+    pub fn with_wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.layout.wrapping = wrapping;
+        self
+    }
+}
+
 /// Grid-specific interface provides several coordinate-related methods.
 impl<I: Index> Grid<I> {
-    /// Return the index of a node at the given coordinates.
+    /// Return the index of a node at the given coordinates. Removed cells have no index.
     pub fn at(&self, row: usize, column: usize) -> Option<I> {
-        self.grid.get(row).and_then(|row| row.get(column)).copied()
+        self.grid
+            .get(row)
+            .and_then(|row| row.get(column))
+            .copied()
+            .flatten()
     }
 
     /// Return the coordinates of the node by the given index.
@@ -92,29 +133,142 @@ impl<I: Index> Grid<I> {
 
     /// Return the coordinates of all neighbors to a vertex at the given coordinates.
     /// This method is primarily used internal buy can be used to simplify handling
-    /// of edge and corner vertices.
+    /// of edge and corner vertices. Neighbors are defined by the grid's neighborhood
+    /// and wrapping, regardless of removed cells and walls.
     pub fn neighbors_of(&self, row: usize, col: usize) -> impl Iterator<Item = Coords> + '_ {
-        [(1, 0), (-1, 0), (0, 1), (0, -1)]
-            .into_iter()
-            .filter_map(move |pair| {
-                pair.bimap(
-                    |dy| row.checked_add_signed(dy),
-                    |dx| col.checked_add_signed(dx),
-                )
-                .collapse(Coords)
+        self.layout
+            .steps_of(Coords(row, col))
+            .map(|(_, coords)| coords)
+    }
+
+    /// Return a view of the rectangular window of the grid with the given ranges of rows and
+    /// columns. See [`Region`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window does not fit in the grid.
+    pub fn region(
+        &self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> Region<'_, I> {
+        let rows = region::resolve(rows, self.layout.rows);
+        let columns = region::resolve(columns, self.layout.columns);
+        Region::new(self, rows, columns)
+    }
+
+    /// Slide a window of the given size over the grid, producing a region for every position
+    /// where the window fits, in row-major order of their top left corners. This is useful for
+    /// convolution-style passes. No regions are produced if the window is larger than the grid.
+    pub fn windows(&self, rows: usize, columns: usize) -> impl Iterator<Item = Region<'_, I>> {
+        let positions = |size: usize, len: usize| match size <= len {
+            true => 0..len - size + 1,
+            false => 0..0,
+        };
+        let row_positions = positions(rows, self.layout.rows);
+        let col_positions = positions(columns, self.layout.columns);
+        row_positions.flat_map(move |row| {
+            col_positions
+                .clone()
+                .map(move |col| Region::new(self, row..row + rows, col..col + columns))
+        })
+    }
+
+    /// Return coordinates of cells linked to a vertex at the given coordinates, skipping
+    /// removed cells, cells behind walls, and cut corners.
+    fn links_of(&self, row: usize, col: usize) -> impl Iterator<Item = (Coords, I)> + '_ {
+        let origin = Coords(row, col);
+        let source = self.at(row, col);
+        self.layout
+            .steps_of(origin)
+            .filter(move |(offset, _)| !self.cuts_corner(origin, *offset))
+            .filter_map(move |(_, coords)| {
+                let (a, b) = source.zip(self.at(coords.0, coords.1))?;
+                (!self.has_wall(a, b)).then_some((coords, b))
             })
-            .filter(|coords| coords.0 < self.rows && coords.1 < self.columns)
+    }
+
+    /// Return indices of vertices linked to a vertex, or `None` if there is no such vertex.
+    fn adjacent_to(&self, id: I) -> Option<impl Iterator<Item = I> + '_> {
+        let Coords(row, col) = self.coords_of(id)?;
+        Some(self.links_of(row, col).map(|(_, id)| id))
+    }
+
+    fn has_wall(&self, a: I, b: I) -> bool {
+        !self.walls.is_empty() && self.walls.contains(&Edge::new(a, b))
+    }
+
+    /// Check whether a diagonal step to a cell sharing a corner passes a removed cell.
+    fn cuts_corner(&self, origin: Coords, (dy, dx): Offset) -> bool {
+        let blocked = |offset| match self.layout.shift(origin, offset) {
+            Some(Coords(row, col)) => self.at(row, col).is_none(),
+            None => true,
+        };
+        let diagonal = dy.abs() == 1 && dx.abs() == 1;
+        !self.corner_cutting && diagonal && (blocked((dy, 0)) || blocked((0, dx)))
+    }
+
+    /// Check whether vertices are linked by an edge.
+    fn are_linked(&self, a: I, b: I) -> bool {
+        match (self.coords_of(a), self.coords_of(b)) {
+            (Some(ca), Some(cb)) => match self.layout.offset_between(ca, cb) {
+                Some(offset) => !self.has_wall(a, b) && !self.cuts_corner(ca, offset),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Check whether vertices are present and neighbors in the grid, regardless of walls.
+    fn are_neighbors(&self, a: I, b: I) -> bool {
+        match (self.coords_of(a), self.coords_of(b)) {
+            (Some(a), Some(b)) => self.layout.offset_between(a, b).is_some(),
+            _ => false,
+        }
     }
 }
 
-fn adjacent(ax: usize, ay: usize, bx: usize, by: usize) -> bool {
-    match 0 {
-        _ if ax == bx && ay < by => by - ay == 1,
-        _ if ax == bx && ay > by => ay - by == 1,
-        _ if ay == by && ax < bx => bx - ax == 1,
-        _ if ay == by && ax > bx => ax - bx == 1,
-        _ => false,
+impl<I: Index> Construct<I> for Grid<I> {
+    /// Restore a previously removed cell. Vertices cannot be added to other places.
+    fn add(&mut self, id: I) -> bool {
+        match self.removed.remove(&id) {
+            Some(coords) => {
+                self.grid[coords.0][coords.1] = Some(id);
+                self.coords.insert(id, coords);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the wall between two neighboring cells.
+    fn link(&mut self, a: I, b: I) -> bool {
+        self.are_neighbors(a, b) && self.walls.remove(&Edge::new(a, b))
+    }
+
+    /// Block a cell, so it is no longer a vertex of the graph. Walls around the cell are kept
+    /// and apply again after the cell is restored.
+    fn remove(&mut self, id: I) -> bool {
+        match self.coords.remove(&id) {
+            Some(coords) => {
+                self.grid[coords.0][coords.1] = None;
+                self.removed.insert(id, coords);
+                true
+            }
+            None => false,
+        }
     }
+
+    /// Put a wall between two neighboring cells.
+    fn unlink(&mut self, a: I, b: I) -> bool {
+        self.are_neighbors(a, b) && self.walls.insert(Edge::new(a, b))
+    }
+}
+
+fn adjacent(offsets: &[Offset], a: Coords, b: Coords) -> bool {
+    let dy = b.0 as isize - a.0 as isize;
+    let dx = b.1 as isize - a.1 as isize;
+    offsets.contains(&(dy, dx))
 }
 
 // Vertex and edge providers
@@ -132,6 +286,18 @@ impl<I: Index> VertexProvider<I> for Grid<I> {
     fn vertices(&self) -> Self::Vertices<'_> {
         Vertices { grid: self }
     }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        self.adjacent_to(item).map(Iterator::count)
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
 }
 
 impl<I: Index> EdgeProvider<I> for Grid<I> {
@@ -142,7 +308,10 @@ impl<I: Index> EdgeProvider<I> for Grid<I> {
     = impl Topology<Item = Self::Edge>;
 
     fn size(&self) -> usize {
-        self.rows * (self.columns - 1) + self.columns * (self.rows - 1)
+        match self.removed.is_empty() && self.walls.is_empty() {
+            true => self.layout.size(),
+            false => self.edges().iter().count(),
+        }
     }
 
     fn edges(&self) -> Self::Edges<'_> {
@@ -173,12 +342,7 @@ impl<'a, I: Index> Topology for Vertices<'a, I> {
     }
 
     fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
-        let Coords(row, column) = self.grid.coords_of(item)?;
-        let iter = self
-            .grid
-            .neighbors_of(row, column)
-            .map(|Coords(row, column)| self.grid.at(row, column).unwrap());
-        Some(iter)
+        self.grid.adjacent_to(item)
     }
 
     fn contains(&self, item: Self::Item) -> bool {
@@ -205,68 +369,44 @@ impl<'a, I: Index> Topology for Edges<'a, I> {
     = impl Iterator<Item = Self::Item>;
 
     fn iter(&self) -> Self::ItemIter<'_> {
-        let grid = &self.grid.grid;
-        let by_rows = (0..self.grid.rows).flat_map(move |row| {
-            (0..self.grid.columns).tuple_windows().map(move |(a, b)| {
-                let a = grid[row][a];
-                let b = grid[row][b];
-                Edge::new(a, b)
-            })
-        });
-        let by_columns = (0..self.grid.columns).flat_map(move |column| {
-            (0..self.grid.rows).tuple_windows().map(move |(a, b)| {
-                let a = grid[a][column];
-                let b = grid[b][column];
-                Edge::new(a, b)
-            })
-        });
-        by_rows.chain(by_columns)
+        let grid = self.grid;
+        grid.layout.cells().flat_map(move |Coords(row, col)| {
+            grid.links_of(row, col)
+                .filter(move |(coords, _)| (coords.0, coords.1) > (row, col))
+                .map(move |(_, id)| Edge::new(grid.at(row, col).unwrap(), id))
+        })
     }
 
     fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
         let Edge(UnorderedPair(a, b)) = item;
-        let a_neighbors = outbound_edges(self.grid, a, b)?;
-        let b_neighbors = outbound_edges(self.grid, b, a)?;
+        let a_neighbors = outbound_edges(a, b, self.grid.adjacent_to(a)?);
+        let b_neighbors = outbound_edges(b, a, self.grid.adjacent_to(b)?);
         Some(a_neighbors.chain(b_neighbors))
     }
 
     fn contains(&self, item: Self::Item) -> bool {
-        let item = item.0;
-        let a = self.grid.coords_of(item.0);
-        let b = self.grid.coords_of(item.1);
-        (a, b)
-            .collapse(|a, b| adjacent(a.1, a.0, b.1, b.0))
-            .unwrap_or_default()
+        let UnorderedPair(a, b) = item.0;
+        self.grid.are_linked(a, b)
     }
 }
 
-fn outbound_edges<I: Index>(
-    grid: &Grid<I>,
-    source: I,
-    exclude: I,
-) -> Option<impl Iterator<Item = Edge<I>> + '_> {
-    let Coords(row, column) = grid.coords_of(source)?;
-    let vertices = grid
-        .neighbors_of(row, column)
-        .map(|Coords(row, column)| grid.at(row, column).unwrap())
-        .filter(move |target| *target != exclude)
-        .map(move |target| Edge::new(source, target));
-    Some(vertices)
-}
-
 /// Additional grid-specific traversals.
 impl<I: Index> Grid<I> {
     /// An additional traversal of the graph's vertices by rows. Each row is processed
     /// in order, and for each row, indices of all its vertices in order are traversed.
     pub fn traverse_by_rows(&self) -> impl Iterator<Item = I> + '_ {
-        let start = self.at(0, 0).unwrap();
-        let last_row = self.rows - 1;
-        let last_col = self.columns - 1;
-        std::iter::successors(Some(start), move |id| match self.coords_of(*id).unwrap() {
-            Coords(row, col) if row == last_row && col == last_col => None,
-            Coords(row, col) if col == last_col => self.at(row + 1, 0),
-            Coords(row, col) => self.at(row, col + 1),
-        })
+        self.grid.iter().flatten().flatten().copied()
+    }
+
+    orders::traversals!(I);
+
+    /// Map an order of cells to indices of vertices, skipping removed cells.
+    fn traverse<'a, T>(&'a self, order: impl Fn(usize, usize) -> T) -> impl Iterator<Item = I> + 'a
+    where
+        T: Iterator<Item = Coords> + 'a,
+    {
+        let Layout { rows, columns, .. } = self.layout;
+        order(rows, columns).filter_map(|Coords(row, col)| self.at(row, col))
     }
 }
 
@@ -277,6 +417,8 @@ mod tests {
 
     #[test]
     fn adjacent_test() {
+        let offsets = Neighborhood::VonNeumann.offsets();
+        let adjacent = |ax, ay, bx, by| adjacent(&offsets, Coords(ay, ax), Coords(by, bx));
         assert!(adjacent(5, 3, 5, 4));
         assert!(adjacent(5, 4, 5, 3));
         assert!(adjacent(3, 5, 4, 5));
@@ -289,6 +431,20 @@ mod tests {
         assert!(!adjacent(5, 3, 6, 4));
     }
 
+    #[test]
+    fn adjacent_follows_stencil() {
+        let moore = Neighborhood::Moore.offsets();
+        let knight = Neighborhood::Knight.offsets();
+        assert!(adjacent(&moore, Coords(3, 5), Coords(4, 6)));
+        assert!(adjacent(&moore, Coords(4, 6), Coords(3, 5)));
+        assert!(!adjacent(&moore, Coords(3, 5), Coords(5, 5)));
+        assert!(adjacent(&knight, Coords(3, 5), Coords(5, 4)));
+        assert!(!adjacent(&knight, Coords(3, 5), Coords(4, 5)));
+        let custom = Neighborhood::Custom(vec![(0, 0), (0, 2)]).offsets();
+        assert_eq!(custom, vec![(0, 2), (0, -2)]);
+        assert!(adjacent(&custom, Coords(1, 3), Coords(1, 1)));
+    }
+
     #[test]
     fn edges_are_bidirectional() {
         let e1 = Edge::new(1, 2);
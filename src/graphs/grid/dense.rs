@@ -0,0 +1,215 @@
+use super::{orders, Coords, Edge, Layout, Neighborhood, Wrapping};
+use crate::graphs::edge::outbound_edges;
+use crate::providers::*;
+use crate::topology::*;
+use crate::utils::{UnorderedBuildHasher, UnorderedPair};
+
+use std::collections::hash_map::RandomState;
+
+/// A variant of [`Grid`](super::Grid) for large maps, where the vertex at row `r` and column `c`
+/// has the index `r * columns + c`. Nothing is stored per vertex, so [`DenseGrid::at`] and
+/// [`DenseGrid::coords_of`] are pure arithmetic and vertices are iterated as a range. Vertex
+/// indices can be used to address plain vectors of vertex data.
+///
+/// The grid supports the same neighborhoods and wrapping modes as `Grid`, but it cannot be
+/// modified, so there are no removed cells or walls.
+#[derive(Clone, Debug)]
+pub struct DenseGrid {
+    layout: Layout,
+}
+
+impl DenseGrid {
+    /// Create a new grid with the given size.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            layout: Layout::new(rows, columns),
+        }
+    }
+
+    /// Replace the neighborhood that defines which cells are linked by edges.
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.layout.offsets = neighborhood.offsets();
+        self
+    }
+
+    /// Glue the borders of the grid together. See [`Wrapping`] for the handling of narrow grids.
+    pub fn with_wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.layout.wrapping = wrapping;
+        self
+    }
+
+    /// Return the number of rows.
+    pub fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    /// Return the number of columns.
+    pub fn columns(&self) -> usize {
+        self.layout.columns
+    }
+
+    /// Return the index of a node at the given coordinates.
+    pub fn at(&self, row: usize, column: usize) -> Option<usize> {
+        let inside = row < self.layout.rows && column < self.layout.columns;
+        inside.then_some(row * self.layout.columns + column)
+    }
+
+    /// Return the coordinates of the node by the given index.
+    pub fn coords_of(&self, id: usize) -> Option<Coords> {
+        let columns = self.layout.columns;
+        (id < self.layout.rows * columns).then(|| Coords(id / columns, id % columns))
+    }
+
+    /// Return the coordinates of all neighbors to a vertex at the given coordinates.
+    pub fn neighbors_of(&self, row: usize, col: usize) -> impl Iterator<Item = Coords> + '_ {
+        self.layout
+            .steps_of(Coords(row, col))
+            .map(|(_, coords)| coords)
+    }
+
+    /// Traverse vertices by rows, which is the order of their indices.
+    pub fn traverse_by_rows(&self) -> impl Iterator<Item = usize> {
+        0..self.layout.rows * self.layout.columns
+    }
+
+    orders::traversals!(usize);
+
+    /// Map an order of cells to indices of vertices.
+    fn traverse<'a, T>(
+        &'a self,
+        order: impl Fn(usize, usize) -> T,
+    ) -> impl Iterator<Item = usize> + 'a
+    where
+        T: Iterator<Item = Coords> + 'a,
+    {
+        order(self.layout.rows, self.layout.columns).map(|coords| self.index(coords))
+    }
+
+    fn index(&self, Coords(row, col): Coords) -> usize {
+        row * self.layout.columns + col
+    }
+
+    /// Return indices of vertices adjacent to a vertex, or `None` if there is no such vertex.
+    fn adjacent_to(&self, id: usize) -> Option<impl Iterator<Item = usize> + '_> {
+        let Coords(row, col) = self.coords_of(id)?;
+        Some(self.neighbors_of(row, col).map(|coords| self.index(coords)))
+    }
+}
+
+// Vertex and edge providers
+
+impl VertexProvider<usize> for DenseGrid {
+    type Vertices<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = usize>;
+
+    fn order(&self) -> usize {
+        self.layout.rows * self.layout.columns
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        Vertices { grid: self }
+    }
+
+    fn degree(&self, item: usize) -> Option<usize> {
+        let Coords(row, col) = self.coords_of(item)?;
+        Some(self.neighbors_of(row, col).count())
+    }
+
+    fn out_degree(&self, item: usize) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: usize) -> Option<usize> {
+        self.degree(item)
+    }
+}
+
+impl EdgeProvider<usize> for DenseGrid {
+    type Edge = Edge<usize>;
+    type Edges<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = Self::Edge>;
+
+    fn size(&self) -> usize {
+        self.layout.size()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        Edges { grid: self }
+    }
+}
+
+// Vertex topology
+
+struct Vertices<'a> {
+    grid: &'a DenseGrid,
+}
+
+impl<'a> Topology for Vertices<'a> {
+    type Item = usize;
+    type BuildHasher = RandomState;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        self.grid.traverse_by_rows()
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        self.grid.adjacent_to(item)
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        item < self.grid.order()
+    }
+}
+
+// Edge topology
+
+struct Edges<'a> {
+    grid: &'a DenseGrid,
+}
+
+impl<'a> Topology for Edges<'a> {
+    type Item = Edge<usize>;
+    type BuildHasher = UnorderedBuildHasher;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        self.grid
+            .layout
+            .pairs()
+            .map(|(a, b)| Edge::new(self.grid.index(a), self.grid.index(b)))
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        let Edge(UnorderedPair(a, b)) = item;
+        let a_neighbors = outbound_edges(a, b, self.grid.adjacent_to(a)?);
+        let b_neighbors = outbound_edges(b, a, self.grid.adjacent_to(b)?);
+        Some(a_neighbors.chain(b_neighbors))
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        let UnorderedPair(a, b) = item.0;
+        match (self.grid.coords_of(a), self.grid.coords_of(b)) {
+            (Some(a), Some(b)) => self.grid.layout.offset_between(a, b).is_some(),
+            _ => false,
+        }
+    }
+}
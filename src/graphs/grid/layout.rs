@@ -0,0 +1,95 @@
+use super::{adjacent, Coords, Neighborhood, Offset, Wrapping};
+
+/// Geometry of a rectangular grid: its size, the neighborhood stencil and the wrapping mode.
+/// It defines neighbors of cells regardless of their contents.
+#[derive(Clone, Debug)]
+pub(super) struct Layout {
+    pub rows: usize,
+    pub columns: usize,
+    pub offsets: Vec<Offset>,
+    pub wrapping: Wrapping,
+}
+
+impl Layout {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            offsets: Neighborhood::VonNeumann.offsets(),
+            wrapping: Wrapping::None,
+        }
+    }
+
+    /// Iterate over all cells in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = Coords> + '_ {
+        (0..self.rows).flat_map(move |row| (0..self.columns).map(move |col| Coords(row, col)))
+    }
+
+    /// Return distinct neighbors of a cell along with offsets leading to them.
+    pub fn steps_of(&self, origin: Coords) -> impl Iterator<Item = (Offset, Coords)> + '_ {
+        self.offsets
+            .iter()
+            .enumerate()
+            .filter_map(move |(k, offset)| {
+                let target = self.shift(origin, *offset)?;
+                let repeated = self.offsets[..k]
+                    .iter()
+                    .any(|offset| self.shift(origin, *offset) == Some(target));
+                (target != origin && !repeated).then_some((*offset, target))
+            })
+    }
+
+    /// Return coordinates of the cell at the given offset, taking wrapping into account.
+    pub fn shift(&self, Coords(row, col): Coords, (dy, dx): Offset) -> Option<Coords> {
+        let step = |position: usize, delta: isize, length: usize, wraps: bool| match wraps {
+            true if length > 0 => {
+                let position = (position as isize + delta).rem_euclid(length as isize);
+                Some(position as usize)
+            }
+            _ => position.checked_add_signed(delta).filter(|p| *p < length),
+        };
+        let row = step(row, dy, self.rows, self.wrapping.vertical())?;
+        let col = step(col, dx, self.columns, self.wrapping.horizontal())?;
+        Some(Coords(row, col))
+    }
+
+    /// Find the offset leading from one cell to its neighbor.
+    pub fn offset_between(&self, a: Coords, b: Coords) -> Option<Offset> {
+        match self.wrapping {
+            Wrapping::None => {
+                let offset = (b.0 as isize - a.0 as isize, b.1 as isize - a.1 as isize);
+                adjacent(&self.offsets, a, b).then_some(offset)
+            }
+            _ => self
+                .steps_of(a)
+                .find(|(_, coords)| *coords == b)
+                .map(|(offset, _)| offset),
+        }
+    }
+
+    /// Iterate over all pairs of neighbors, producing each pair once.
+    pub fn pairs(&self) -> impl Iterator<Item = (Coords, Coords)> + '_ {
+        self.cells().flat_map(move |origin| {
+            self.steps_of(origin)
+                .filter(move |(_, target)| (target.0, target.1) > (origin.0, origin.1))
+                .map(move |(_, target)| (origin, target))
+        })
+    }
+
+    /// Return the number of pairs of neighbors.
+    pub fn size(&self) -> usize {
+        // Without wrapping, every offset pair gives the same number of edges in every window.
+        match self.wrapping {
+            Wrapping::None => self
+                .offsets
+                .iter()
+                .filter(|offset| **offset > (0, 0))
+                .map(|(dy, dx)| {
+                    let rows = self.rows.saturating_sub(dy.unsigned_abs());
+                    rows * self.columns.saturating_sub(dx.unsigned_abs())
+                })
+                .sum(),
+            _ => self.pairs().count(),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Maze generation on [`Grid`]. Every algorithm picks a random spanning tree of the grid's edge
+//! topology, so it respects the neighborhood, wrapping, removed cells and walls of the grid.
+//! The result is a set of open passages, which can be turned into a grid with walls in place of
+//! all other edges by [`walled`].
+//!
+//! Random choices are drawn from the given `rng`. Cells are visited in the order of their
+//! coordinates, so seeding the generator makes the maze reproducible for grids of the same shape.
+//! If removed cells split the grid in several parts, the maze is a spanning forest with a tree in
+//! every part.
+//!
+//! This module is only available with the `rand` feature.
+
+use super::{Coords, Edge, EdgeSet, Grid};
+use crate::construct::Construct;
+use crate::index::*;
+use crate::providers::*;
+use crate::topology::*;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Generate a maze with the recursive backtracker, which is a randomized depth-first search.
+/// It produces long winding corridors with few dead ends.
+///
+/// The search is written out here rather than built on [`dfs`](crate::traversal::dfs): that one
+/// yields vertices in preorder without the tree edges that become passages, visits neighbors in
+/// the order of the topology instead of a random one, and marks vertices as discovered when they
+/// are pushed, so its order is not the one of a backtracking walk.
+pub fn recursive_backtracker<I: Index, R: Rng>(grid: &Grid<I>, rng: &mut R) -> EdgeSet<I> {
+    let cells = Cells::new(grid);
+    let mut passages = EdgeSet::default();
+    let mut visited = vec![false; cells.len()];
+    for root in cells.roots(rng) {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![root];
+        while let Some(&v) = stack.last() {
+            let unvisited = cells.adjacency[v]
+                .iter()
+                .filter(|u| !visited[**u])
+                .collect::<Vec<_>>();
+            match unvisited.choose(rng) {
+                Some(&&u) => {
+                    visited[u] = true;
+                    passages.insert(cells.edge(v, u));
+                    stack.push(u);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+    passages
+}
+
+/// Generate a maze with the randomized Kruskal's algorithm, opening edges in random order unless
+/// they would close a loop. It produces many short dead ends.
+pub fn kruskal<I: Index, R: Rng>(grid: &Grid<I>, rng: &mut R) -> EdgeSet<I> {
+    let cells = Cells::new(grid);
+    let mut edges = cells.pairs().collect::<Vec<_>>();
+    edges.shuffle(rng);
+    let mut sets = Sets::new(cells.len());
+    edges
+        .into_iter()
+        .filter(|(v, u)| sets.union(*v, *u))
+        .map(|(v, u)| cells.edge(v, u))
+        .collect()
+}
+
+/// Generate a maze with the randomized Prim's algorithm, growing the maze from a random cell by
+/// opening a random edge on its frontier. It produces many short dead ends around the start.
+pub fn prim<I: Index, R: Rng>(grid: &Grid<I>, rng: &mut R) -> EdgeSet<I> {
+    let cells = Cells::new(grid);
+    let mut passages = EdgeSet::default();
+    let mut visited = vec![false; cells.len()];
+    for root in cells.roots(rng) {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut frontier = cells.adjacency[root]
+            .iter()
+            .map(|u| (root, *u))
+            .collect::<Vec<_>>();
+        while !frontier.is_empty() {
+            let (v, u) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+            if visited[u] {
+                continue;
+            }
+            visited[u] = true;
+            passages.insert(cells.edge(v, u));
+            let edges = cells.adjacency[u].iter().filter(|w| !visited[**w]);
+            frontier.extend(edges.map(|w| (u, *w)));
+        }
+    }
+    passages
+}
+
+/// Generate a maze with Wilson's algorithm, joining loop-erased random walks to the maze. Every
+/// spanning tree is equally likely, so the maze has no bias, but the first walks may be long.
+pub fn wilson<I: Index, R: Rng>(grid: &Grid<I>, rng: &mut R) -> EdgeSet<I> {
+    let cells = Cells::new(grid);
+    let mut components = Sets::new(cells.len());
+    for (v, u) in cells.pairs() {
+        components.union(v, u);
+    }
+
+    let mut passages = EdgeSet::default();
+    let mut in_maze = vec![false; cells.len()];
+    let mut rooted = vec![false; cells.len()];
+    let mut next = vec![0; cells.len()];
+    for start in cells.roots(rng) {
+        let component = components.find(start);
+        if !rooted[component] {
+            rooted[component] = true;
+            in_maze[start] = true;
+        }
+        // Later steps from a cell overwrite earlier ones, which erases loops of the walk.
+        let mut v = start;
+        while !in_maze[v] {
+            next[v] = *cells.adjacency[v].choose(rng).unwrap();
+            v = next[v];
+        }
+        let mut v = start;
+        while !in_maze[v] {
+            in_maze[v] = true;
+            passages.insert(cells.edge(v, next[v]));
+            v = next[v];
+        }
+    }
+    passages
+}
+
+/// Generate a maze with Eller's algorithm, which builds the maze row by row. Cells of a row are
+/// randomly joined with their right neighbors, and every group of joined cells opens at least one
+/// passage down. It only needs to keep track of a single row, but is limited to horizontal and
+/// vertical links between cells in consecutive rows and columns, ignoring other neighbors and
+/// wrapping. Groups that cannot go down due to removed cells or walls are closed off, so grids
+/// with obstacles may get a spanning forest with more trees.
+pub fn eller<I: Index, R: Rng>(grid: &Grid<I>, rng: &mut R) -> EdgeSet<I> {
+    let (rows, columns) = (grid.layout.rows, grid.layout.columns);
+    let edges = grid.edges();
+    let link = |a: Coords, b: Coords| {
+        let pair = grid.at(a.0, a.1).zip(grid.at(b.0, b.1))?;
+        edges.contains(Edge::new(pair.0, pair.1)).then_some(pair)
+    };
+    let position = |row: usize, col: usize| row * columns + col;
+
+    let mut passages = EdgeSet::default();
+    let mut sets = Sets::new(rows * columns);
+    for row in 0..rows {
+        let last = row + 1 == rows;
+        for col in 1..columns {
+            let (a, b) = (position(row, col - 1), position(row, col));
+            if let Some((left, right)) = link(Coords(row, col - 1), Coords(row, col)) {
+                if sets.find(a) != sets.find(b) && (last || rng.gen()) {
+                    sets.union(a, b);
+                    passages.insert(Edge::new(left, right));
+                }
+            }
+        }
+        if last {
+            break;
+        }
+
+        // Group cells having a passage down by their sets, in the order of columns.
+        let mut groups: Vec<Vec<(usize, (I, I))>> = Vec::new();
+        let mut group_of = HashMap::new();
+        for col in 0..columns {
+            if let Some(pair) = link(Coords(row, col), Coords(row + 1, col)) {
+                let set = sets.find(position(row, col));
+                let group = *group_of.entry(set).or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+                groups[group].push((col, pair));
+            }
+        }
+        for group in groups {
+            let forced = rng.gen_range(0..group.len());
+            for (k, (col, (top, bottom))) in group.into_iter().enumerate() {
+                if k == forced || rng.gen() {
+                    sets.union(position(row, col), position(row + 1, col));
+                    passages.insert(Edge::new(top, bottom));
+                }
+            }
+        }
+    }
+    passages
+}
+
+/// Put walls between all linked cells of the grid, except for the given passages.
+pub fn walled<I: Index>(mut grid: Grid<I>, passages: &EdgeSet<I>) -> Grid<I> {
+    let walls = grid
+        .edges()
+        .iter()
+        .filter(|edge| !passages.contains(edge))
+        .collect::<Vec<_>>();
+    for Edge(pair) in walls {
+        grid.unlink(pair.0, pair.1);
+    }
+    grid
+}
+
+/// Cells of a grid in row-major order, addressed by their positions in this order.
+struct Cells<I> {
+    ids: Vec<I>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<I: Index> Cells<I> {
+    fn new(grid: &Grid<I>) -> Self {
+        let ids = grid.traverse_by_rows().collect::<Vec<_>>();
+        let positions = ids
+            .iter()
+            .enumerate()
+            .map(|(v, id)| (*id, v))
+            .collect::<HashMap<_, _>>();
+        let vertices = grid.vertices();
+        let adjacency = ids
+            .iter()
+            .map(|id| match vertices.adjacent_to(*id) {
+                Some(adjacent) => adjacent.map(|id| positions[&id]).collect(),
+                None => Vec::new(),
+            })
+            .collect();
+        Self { ids, adjacency }
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn edge(&self, v: usize, u: usize) -> Edge<I> {
+        Edge::new(self.ids[v], self.ids[u])
+    }
+
+    /// Iterate over all pairs of linked cells, producing each pair once.
+    fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency.iter().enumerate().flat_map(|(v, adjacent)| {
+            adjacent
+                .iter()
+                .filter(move |u| v < **u)
+                .map(move |u| (v, *u))
+        })
+    }
+
+    /// Return a random cell to start the maze, followed by all cells to cover other parts.
+    fn roots<R: Rng>(&self, rng: &mut R) -> impl Iterator<Item = usize> {
+        let start = (self.len() > 0).then(|| rng.gen_range(0..self.len()));
+        start.into_iter().chain(0..self.len())
+    }
+}
+
+/// Disjoint sets of positions.
+struct Sets {
+    parents: Vec<usize>,
+}
+
+impl Sets {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, mut v: usize) -> usize {
+        while self.parents[v] != v {
+            self.parents[v] = self.parents[self.parents[v]];
+            v = self.parents[v];
+        }
+        v
+    }
+
+    /// Merge sets of two positions, returning `false` if they are already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+        a != b
+    }
+}
@@ -0,0 +1,92 @@
+/// Offsets of the neighbors of a cell in row-column order.
+pub type Offset = (isize, isize);
+
+const VON_NEUMANN: [Offset; 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const MOORE: [Offset; 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+const KNIGHT: [Offset; 8] = [
+    (2, 1),
+    (2, -1),
+    (-2, 1),
+    (-2, -1),
+    (1, 2),
+    (1, -2),
+    (-1, 2),
+    (-1, -2),
+];
+
+/// Defines which cells of a [`Grid`](super::Grid) are neighbors, and thus linked by edges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Cells sharing a side, at most 4 neighbors.
+    #[default]
+    VonNeumann,
+    /// Cells sharing a side or a corner, at most 8 neighbors.
+    Moore,
+    /// Cells a chess knight can move to, at most 8 neighbors.
+    Knight,
+    /// Arbitrary offsets. Edges are bidirectional, so the stencil is completed with the opposite
+    /// of every offset, and the zero offset is ignored.
+    Custom(Vec<Offset>),
+}
+
+impl Neighborhood {
+    /// Return the symmetric set of offsets without the zero offset.
+    pub fn offsets(&self) -> Vec<Offset> {
+        match self {
+            Neighborhood::VonNeumann => VON_NEUMANN.to_vec(),
+            Neighborhood::Moore => MOORE.to_vec(),
+            Neighborhood::Knight => KNIGHT.to_vec(),
+            Neighborhood::Custom(stencil) => {
+                let mut offsets = Vec::with_capacity(2 * stencil.len());
+                for &(dy, dx) in stencil.iter().filter(|offset| **offset != (0, 0)) {
+                    for offset in [(dy, dx), (-dy, -dx)] {
+                        if !offsets.contains(&offset) {
+                            offsets.push(offset);
+                        }
+                    }
+                }
+                offsets
+            }
+        }
+    }
+}
+
+/// Defines which borders of a [`Grid`](super::Grid) are glued together, so that steps over them
+/// lead to the opposite side of the grid.
+///
+/// Wrapping never creates self-loops or parallel edges. In a grid that is one cell wide along
+/// a wrapped axis, steps along it lead back to the same cell and are dropped, and in a grid that
+/// is two cells wide, steps in both directions lead to the same neighbor, which is linked once.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Wrapping {
+    /// Borders are not glued.
+    #[default]
+    None,
+    /// The first and the last columns are neighbors.
+    Horizontal,
+    /// The first and the last rows are neighbors.
+    Vertical,
+    /// Both of the above, turning the grid into a torus.
+    Both,
+}
+
+impl Wrapping {
+    /// Check whether the first and the last columns are neighbors.
+    pub fn horizontal(self) -> bool {
+        matches!(self, Wrapping::Horizontal | Wrapping::Both)
+    }
+
+    /// Check whether the first and the last rows are neighbors.
+    pub fn vertical(self) -> bool {
+        matches!(self, Wrapping::Vertical | Wrapping::Both)
+    }
+}
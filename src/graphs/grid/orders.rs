@@ -0,0 +1,178 @@
+//! Orders of cells of a rectangle with the given number of rows and columns, which are used to
+//! implement grid traversals.
+
+use super::Coords;
+
+pub(super) fn by_columns(rows: usize, columns: usize) -> impl Iterator<Item = Coords> {
+    (0..columns).flat_map(move |col| (0..rows).map(move |row| Coords(row, col)))
+}
+
+pub(super) fn serpentine(rows: usize, columns: usize) -> impl Iterator<Item = Coords> {
+    (0..rows).flat_map(move |row| {
+        (0..columns).map(move |k| match row % 2 {
+            0 => Coords(row, k),
+            _ => Coords(row, columns - 1 - k),
+        })
+    })
+}
+
+/// Clockwise spiral from the top left corner towards the center. The iterator is double-ended,
+/// so the outward spiral is its reverse.
+pub(super) fn spiral(rows: usize, columns: usize) -> impl DoubleEndedIterator<Item = Coords> {
+    let layers = rows.min(columns).div_ceil(2);
+    (0..layers).flat_map(move |k| {
+        let (top, bottom, left, right) = (k, rows - 1 - k, k, columns - 1 - k);
+        // Bottom row and left column are only present in layers more than one cell thick.
+        let (last_col, first_row) = match bottom > top && right > left {
+            true => (left, top + 1),
+            false => (right + 1, bottom + 1),
+        };
+        let top_row = (left..=right).map(move |col| Coords(top, col));
+        let right_col = (top + 1..=bottom).map(move |row| Coords(row, right));
+        let bottom_row = (last_col..right).rev().map(move |col| Coords(bottom, col));
+        let left_col = (first_row..bottom).rev().map(move |row| Coords(row, left));
+        top_row.chain(right_col).chain(bottom_row).chain(left_col)
+    })
+}
+
+/// Anti-diagonals from the top left corner, each of them from top to bottom.
+pub(super) fn diagonals(rows: usize, columns: usize) -> impl Iterator<Item = Coords> {
+    let count = match rows.min(columns) {
+        0 => 0,
+        _ => rows + columns - 1,
+    };
+    (0..count).flat_map(move |sum| {
+        let first = sum.saturating_sub(columns - 1);
+        (first..=sum.min(rows - 1)).map(move |row| Coords(row, sum - row))
+    })
+}
+
+/// Z-order curve: cells are sorted by interleaved bits of their coordinates. Quadrants that do
+/// not intersect the rectangle are skipped, so the order is cheap for any proportions.
+pub(super) fn morton(rows: usize, columns: usize) -> impl Iterator<Item = Coords> {
+    let side = rows.max(columns).next_power_of_two();
+    let mut stack = match rows * columns {
+        0 => vec![],
+        _ => vec![(0, 0, side)],
+    };
+    std::iter::from_fn(move || loop {
+        let (row, col, side) = stack.pop()?;
+        if side == 1 {
+            return Some(Coords(row, col));
+        }
+        let half = side / 2;
+        let quadrants = [(half, half), (half, 0), (0, half), (0, 0)];
+        for (dy, dx) in quadrants {
+            if row + dy < rows && col + dx < columns {
+                stack.push((row + dy, col + dx, half));
+            }
+        }
+    })
+}
+
+/// Generalized Hilbert curve ("gilbert") by Jakub Červený, which fills rectangles of any size.
+/// Consecutive cells are neighbors, except for a single diagonal step in some rectangles with an
+/// odd side.
+pub(super) fn hilbert(rows: usize, columns: usize) -> impl Iterator<Item = Coords> {
+    let (width, height) = (columns as isize, rows as isize);
+    let mut stack = match (width, height) {
+        (0, _) | (_, 0) => vec![],
+        _ if width >= height => vec![[0, 0, width, 0, 0, height]],
+        _ => vec![[0, 0, 0, height, width, 0]],
+    };
+    // A straight run of cells: position, direction and the number of remaining cells.
+    let mut run = (0, 0, 0, 0, 0);
+    std::iter::from_fn(move || loop {
+        let (x, y, dx, dy, remaining) = run;
+        if remaining > 0 {
+            run = (x + dx, y + dy, dx, dy, remaining - 1);
+            return Some(Coords(y as usize, x as usize));
+        }
+
+        let [x, y, ax, ay, bx, by] = stack.pop()?;
+        let (w, h) = ((ax + ay).abs(), (bx + by).abs());
+        let (dax, day) = (ax.signum(), ay.signum());
+        let (dbx, dby) = (bx.signum(), by.signum());
+        if h == 1 {
+            run = (x, y, dax, day, w);
+            continue;
+        }
+        if w == 1 {
+            run = (x, y, dbx, dby, h);
+            continue;
+        }
+
+        let (mut ax2, mut ay2) = (ax.div_euclid(2), ay.div_euclid(2));
+        let (mut bx2, mut by2) = (bx.div_euclid(2), by.div_euclid(2));
+        let (w2, h2) = ((ax2 + ay2).abs(), (bx2 + by2).abs());
+        // Parts are pushed in reverse, so the first part is processed first.
+        if 2 * w > 3 * h {
+            // Long case: split in two parts along the major direction, preferring even steps.
+            if w2 % 2 == 1 && w > 2 {
+                (ax2, ay2) = (ax2 + dax, ay2 + day);
+            }
+            stack.push([x + ax2, y + ay2, ax - ax2, ay - ay2, bx, by]);
+            stack.push([x, y, ax2, ay2, bx, by]);
+        } else {
+            // Standard case: one step up, one long step along, and one step down.
+            if h2 % 2 == 1 && h > 2 {
+                (bx2, by2) = (bx2 + dbx, by2 + dby);
+            }
+            let x3 = x + (ax - dax) + (bx2 - dbx);
+            let y3 = y + (ay - day) + (by2 - dby);
+            stack.push([x3, y3, -bx2, -by2, -(ax - ax2), -(ay - ay2)]);
+            stack.push([x + bx2, y + by2, ax, ay, bx - bx2, by - by2]);
+            stack.push([x, y, bx2, by2, ax2, ay2]);
+        }
+    })
+}
+
+/// Define the `traverse_*` methods of a grid, except for `traverse_by_rows`, on top of its
+/// private `traverse` method, which maps an order of cells to indices of vertices of type `$I`.
+macro_rules! traversals {
+    ($I:ty) => {
+        /// Traverse vertices by columns, from top to bottom in each column.
+        pub fn traverse_by_columns(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::by_columns)
+        }
+
+        /// Traverse vertices by rows, alternating the direction: even rows are traversed from
+        /// left to right, and odd rows from right to left, so consecutive vertices stay close.
+        pub fn traverse_serpentine(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::serpentine)
+        }
+
+        /// Traverse vertices in a clockwise spiral from the top left corner to the center.
+        pub fn traverse_spiral_inward(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::spiral)
+        }
+
+        /// Traverse vertices in a counterclockwise spiral from the center to the top left
+        /// corner, which is the reverse of [`Self::traverse_spiral_inward`].
+        pub fn traverse_spiral_outward(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(|rows, columns| orders::spiral(rows, columns).rev())
+        }
+
+        /// Traverse vertices by anti-diagonals, a wavefront moving from the top left corner.
+        /// Each anti-diagonal is traversed from top to bottom.
+        pub fn traverse_by_diagonals(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::diagonals)
+        }
+
+        /// Traverse vertices along a Hilbert curve, generalized to rectangles of any size.
+        /// Consecutive vertices are neighbors, except for a single diagonal step in some grids
+        /// with an odd side. Nearby vertices tend to be close in the order, which makes it
+        /// cache-friendly.
+        pub fn traverse_hilbert(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::hilbert)
+        }
+
+        /// Traverse vertices along a Z-order (Morton) curve, visiting quadrants recursively in
+        /// the top left, top right, bottom left and bottom right order.
+        pub fn traverse_morton(&self) -> impl Iterator<Item = $I> + '_ {
+            self.traverse(orders::morton)
+        }
+    };
+}
+
+pub(super) use traversals;
@@ -0,0 +1,249 @@
+use super::{Coords, Edge, Grid};
+use crate::graphs::edge::outbound_edges;
+use crate::index::*;
+use crate::providers::*;
+use crate::topology::*;
+use crate::utils::{UnorderedBuildHasher, UnorderedPair};
+
+use std::collections::hash_map::RandomState;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// A rectangular window into a [`Grid`], created by [`Grid::region`] and [`Grid::windows`].
+/// The region is a graph on its own: it contains the vertices inside the window and the edges
+/// between them, while edges crossing the border of the window are excluded. Nothing is copied,
+/// so regions are cheap to create.
+///
+/// Coordinates accepted and returned by the region are local, with the top left corner of the
+/// window at `Coords(0, 0)`. Vertex indices are the same as in the grid.
+pub struct Region<'a, I> {
+    grid: &'a Grid<I>,
+    rows: Range<usize>,
+    columns: Range<usize>,
+}
+
+impl<'a, I> Clone for Region<'a, I> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+        }
+    }
+}
+
+/// Region-specific interface provides several coordinate-related methods.
+impl<'a, I: Index> Region<'a, I> {
+    pub(super) fn new(grid: &'a Grid<I>, rows: Range<usize>, columns: Range<usize>) -> Self {
+        Self {
+            grid,
+            rows,
+            columns,
+        }
+    }
+
+    /// Return the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Return the number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Return the coordinates of the top left corner of the region in the grid.
+    pub fn origin(&self) -> Coords {
+        Coords(self.rows.start, self.columns.start)
+    }
+
+    /// Return the index of a node at the given local coordinates.
+    pub fn at(&self, row: usize, column: usize) -> Option<I> {
+        let inside = row < self.rows() && column < self.columns();
+        inside
+            .then(|| {
+                self.grid
+                    .at(self.rows.start + row, self.columns.start + column)
+            })
+            .flatten()
+    }
+
+    /// Return the local coordinates of the node by the given index, if it is inside the region.
+    pub fn coords_of(&self, id: I) -> Option<Coords> {
+        let Coords(row, col) = self.grid.coords_of(id)?;
+        self.contains(Coords(row, col))
+            .then(|| Coords(row - self.rows.start, col - self.columns.start))
+    }
+
+    /// Traverse vertices by rows, in the same way as [`Grid::traverse_by_rows`].
+    pub fn traverse_by_rows(&self) -> impl Iterator<Item = I> + '_ {
+        self.cells()
+            .filter_map(|Coords(row, col)| self.grid.at(row, col))
+    }
+
+    /// Iterate over grid coordinates of cells inside the region.
+    fn cells(&self) -> impl Iterator<Item = Coords> + '_ {
+        let columns = self.columns.clone();
+        self.rows
+            .clone()
+            .flat_map(move |row| columns.clone().map(move |col| Coords(row, col)))
+    }
+
+    /// Check whether grid coordinates are inside the region.
+    fn contains(&self, Coords(row, col): Coords) -> bool {
+        self.rows.contains(&row) && self.columns.contains(&col)
+    }
+
+    /// Return indices of vertices inside the region linked to a vertex, or `None` if the vertex
+    /// is not inside the region.
+    fn adjacent_to(&self, id: I) -> Option<impl Iterator<Item = I> + '_> {
+        self.coords_of(id)?;
+        let coords = self.grid.coords_of(id)?;
+        Some(self.links_of(coords).map(|(_, id)| id))
+    }
+
+    /// Return grid coordinates and indices of cells inside the region linked to a cell.
+    fn links_of(&self, Coords(row, col): Coords) -> impl Iterator<Item = (Coords, I)> + '_ {
+        self.grid
+            .links_of(row, col)
+            .filter(move |(coords, _)| self.contains(*coords))
+    }
+}
+
+/// Convert bounds of a range to a range within `0..len`.
+///
+/// # Panics
+///
+/// Panics if the range starts after its end or ends after `len`.
+pub(super) fn resolve(bounds: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match bounds.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "region must be inside the grid");
+    start..end
+}
+
+// Vertex and edge providers
+
+impl<'a, I: Index> VertexProvider<I> for Region<'a, I> {
+    type Vertices<'b>
+    where
+        Self: 'b,
+    = impl Topology<Item = I>;
+
+    fn order(&self) -> usize {
+        self.traverse_by_rows().count()
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        Vertices { region: self }
+    }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        self.adjacent_to(item).map(Iterator::count)
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+}
+
+impl<'a, I: Index> EdgeProvider<I> for Region<'a, I> {
+    type Edge = Edge<I>;
+    type Edges<'b>
+    where
+        Self: 'b,
+    = impl Topology<Item = Self::Edge>;
+
+    fn size(&self) -> usize {
+        self.edges().iter().count()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        Edges { region: self }
+    }
+}
+
+// Vertex topology
+
+struct Vertices<'a, 'b, I> {
+    region: &'b Region<'a, I>,
+}
+
+impl<'a, 'b, I: Index> Topology for Vertices<'a, 'b, I> {
+    type Item = I;
+    type BuildHasher = RandomState;
+    type ItemIter<'c>
+    where
+        Self: 'c,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'c>
+    where
+        Self: 'c,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        self.region.traverse_by_rows()
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        self.region.adjacent_to(item)
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        self.region.coords_of(item).is_some()
+    }
+}
+
+// Edge topology
+
+struct Edges<'a, 'b, I> {
+    region: &'b Region<'a, I>,
+}
+
+impl<'a, 'b, I: Index> Topology for Edges<'a, 'b, I> {
+    type Item = Edge<I>;
+    type BuildHasher = UnorderedBuildHasher;
+    type ItemIter<'c>
+    where
+        Self: 'c,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'c>
+    where
+        Self: 'c,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        let region = self.region;
+        region.cells().flat_map(move |origin| {
+            let source = region.grid.at(origin.0, origin.1);
+            region
+                .links_of(origin)
+                .filter(move |(coords, _)| (coords.0, coords.1) > (origin.0, origin.1))
+                .filter_map(move |(_, id)| Some(Edge::new(source?, id)))
+        })
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        let Edge(UnorderedPair(a, b)) = item;
+        let a_neighbors = outbound_edges(a, b, self.region.adjacent_to(a)?);
+        let b_neighbors = outbound_edges(b, a, self.region.adjacent_to(b)?);
+        Some(a_neighbors.chain(b_neighbors))
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        let UnorderedPair(a, b) = item.0;
+        let inside = |id| self.region.coords_of(id).is_some();
+        inside(a) && inside(b) && self.region.grid.are_linked(a, b)
+    }
+}
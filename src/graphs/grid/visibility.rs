@@ -0,0 +1,174 @@
+use super::{Coords, Grid};
+use crate::index::*;
+
+use std::collections::HashSet;
+
+/// Visibility queries. They work in the plane of the grid, so wrapping is not taken into account.
+/// Removed cells block the view like opaque ones.
+impl<I: Index> Grid<I> {
+    /// Return cells of a Bresenham line between two cells, including both ends.
+    pub fn line(&self, from: Coords, to: Coords) -> impl Iterator<Item = Coords> {
+        let (mut x, mut y) = (from.1 as isize, from.0 as isize);
+        let (x1, y1) = (to.1 as isize, to.0 as isize);
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+        let mut error = dx + dy;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let current = Coords(y as usize, x as usize);
+            done = x == x1 && y == y1;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += sx;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += sy;
+            }
+            Some(current)
+        })
+    }
+
+    /// Check whether the cell `to` can be seen from the cell `from`, that is, no cell of the
+    /// [line](Self::line) between them is opaque. The ends of the line may be opaque themselves.
+    /// Returns `false` if any of the ends is not a vertex of the grid.
+    pub fn has_line_of_sight(
+        &self,
+        from: Coords,
+        to: Coords,
+        is_opaque: impl Fn(I) -> bool,
+    ) -> bool {
+        if self.at(from.0, from.1).is_none() || self.at(to.0, to.1).is_none() {
+            return false;
+        }
+        self.line(from, to)
+            .filter(|coords| *coords != from && *coords != to)
+            .all(|Coords(row, col)| self.at(row, col).is_some_and(|id| !is_opaque(id)))
+    }
+
+    /// Return vertices visible from the `origin` within the Euclidean distance `radius`, using
+    /// symmetric shadowcasting: a cell is visible from another one if and only if the second one
+    /// is visible from the first. Opaque cells are visible, but block the view behind them. The
+    /// origin is always visible if it is a vertex.
+    pub fn field_of_view(
+        &self,
+        origin: Coords,
+        radius: usize,
+        is_opaque: impl Fn(I) -> bool,
+    ) -> HashSet<I> {
+        let mut visible = HashSet::new();
+        let source = match self.at(origin.0, origin.1) {
+            Some(source) => source,
+            None => return visible,
+        };
+        visible.insert(source);
+
+        // Cells farther than the size of the grid are never inside it.
+        let radius = radius.min(self.layout.rows + self.layout.columns) as isize;
+        for quadrant in QUADRANTS {
+            // Transform a tile of the quadrant to a vertex, if there is one.
+            let vertex = |depth: isize, col: isize| {
+                let (dy, dx) = quadrant(depth, col);
+                let row = origin.0.checked_add_signed(dy)?;
+                let col = origin.1.checked_add_signed(dx)?;
+                self.at(row, col)
+            };
+            let is_wall = |tile: Option<(isize, isize)>| match tile {
+                Some((depth, col)) => vertex(depth, col).is_none_or(&is_opaque),
+                None => false,
+            };
+            let is_floor = |tile: Option<(isize, isize)>| tile.is_some() && !is_wall(tile);
+
+            let mut rows = vec![Row {
+                depth: 1,
+                start: Slope(-1, 1),
+                end: Slope(1, 1),
+            }];
+            while let Some(mut row) = rows.pop() {
+                if row.depth > radius {
+                    continue;
+                }
+                let mut previous = None;
+                for col in row.columns() {
+                    let tile = Some((row.depth, col));
+                    let in_radius = row.depth.pow(2) + col.pow(2) <= radius.pow(2);
+                    if in_radius && (is_wall(tile) || row.is_symmetric(col)) {
+                        visible.extend(vertex(row.depth, col));
+                    }
+                    if is_wall(previous) && is_floor(tile) {
+                        row.start = Slope::of(row.depth, col);
+                    }
+                    if is_floor(previous) && is_wall(tile) {
+                        rows.push(Row {
+                            end: Slope::of(row.depth, col),
+                            ..row.next()
+                        });
+                    }
+                    previous = tile;
+                }
+                if is_floor(previous) {
+                    rows.push(row.next());
+                }
+            }
+        }
+        visible
+    }
+}
+
+type Transform = fn(isize, isize) -> (isize, isize);
+
+/// Transformations of `(depth, column)` tiles of quadrants to `(row, column)` offsets.
+const QUADRANTS: [Transform; 4] = [
+    |depth, col| (-depth, col),
+    |depth, col| (col, depth),
+    |depth, col| (depth, col),
+    |depth, col| (col, -depth),
+];
+
+/// A rational slope `numerator / denominator` with a positive denominator.
+#[derive(Copy, Clone)]
+struct Slope(isize, isize);
+
+impl Slope {
+    /// The slope of the left edge of a tile.
+    fn of(depth: isize, col: isize) -> Self {
+        Slope(2 * col - 1, 2 * depth)
+    }
+}
+
+/// A row of a quadrant, scanned between two slopes.
+#[derive(Copy, Clone)]
+struct Row {
+    depth: isize,
+    start: Slope,
+    end: Slope,
+}
+
+impl Row {
+    fn columns(&self) -> std::ops::RangeInclusive<isize> {
+        let Slope(a, b) = self.start;
+        let Slope(c, d) = self.end;
+        // Round `depth * start` with ties up, and `depth * end` with ties down.
+        let first = (2 * self.depth * a + b).div_euclid(2 * b);
+        let last = -(d - 2 * self.depth * c).div_euclid(2 * d);
+        first..=last
+    }
+
+    fn next(&self) -> Self {
+        Self {
+            depth: self.depth + 1,
+            ..*self
+        }
+    }
+
+    /// Check whether the center of the tile is between the slopes.
+    fn is_symmetric(&self, col: isize) -> bool {
+        let Slope(a, b) = self.start;
+        let Slope(c, d) = self.end;
+        col * b >= self.depth * a && col * d <= self.depth * c
+    }
+}
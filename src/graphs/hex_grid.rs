@@ -0,0 +1,354 @@
+//! This module defines a hexagonal grid graph and its related structures.
+
+use super::edge::outbound_edges;
+use crate::index::*;
+use crate::providers::*;
+use crate::topology::*;
+use crate::utils::{UnorderedBuildHasher, UnorderedPair};
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+pub use super::edge::{Edge, EdgeSet};
+
+/// Axial coordinates `(q, r)` of a hexagon. The third cube coordinate `s` is implied by the
+/// `q + r + s == 0` constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Axial(pub isize, pub isize);
+
+/// Offsets to the six neighbors of a hexagon. Opposite directions are three positions apart.
+const DIRECTIONS: [Axial; 6] = [
+    Axial(1, 0),
+    Axial(1, -1),
+    Axial(0, -1),
+    Axial(-1, 0),
+    Axial(-1, 1),
+    Axial(0, 1),
+];
+
+impl Axial {
+    /// Create coordinates from cube coordinates, returning `None` if they violate the
+    /// `q + r + s == 0` constraint.
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Option<Self> {
+        (q + r + s == 0).then_some(Axial(q, r))
+    }
+
+    /// Return cube coordinates `(q, r, s)`.
+    pub fn cube(self) -> (isize, isize, isize) {
+        (self.0, self.1, -self.0 - self.1)
+    }
+
+    /// Return the number of steps between two hexagons.
+    pub fn distance(self, other: Axial) -> usize {
+        let (aq, ar, as_) = self.cube();
+        let (bq, br, bs) = other.cube();
+        (aq.abs_diff(bq) + ar.abs_diff(br) + as_.abs_diff(bs)) / 2
+    }
+
+    /// Return all six neighboring coordinates.
+    pub fn neighbors(self) -> impl Iterator<Item = Axial> {
+        DIRECTIONS.into_iter().map(move |d| self.step(d, 1))
+    }
+
+    /// Iterate over hexagons at exactly the given distance, going around the ring.
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Axial> {
+        let start = self.step(DIRECTIONS[4], radius);
+        let steps = (0..6).flat_map(move |side| (0..radius).map(move |_| DIRECTIONS[side]));
+        let ring = steps.scan(start, |hex, direction| {
+            let current = *hex;
+            *hex = hex.step(direction, 1);
+            Some(current)
+        });
+        let center = (radius == 0).then_some(self);
+        center.into_iter().chain(ring)
+    }
+
+    /// Iterate over hexagons within the given distance, ring by ring from the center outwards.
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Axial> {
+        (0..=radius).flat_map(move |radius| self.ring(radius))
+    }
+
+    fn step(self, Axial(dq, dr): Axial, times: usize) -> Axial {
+        let times = times as isize;
+        Axial(self.0 + dq * times, self.1 + dr * times)
+    }
+}
+
+/// Orientation of hexagons, which defines the pixel layout and rectangular map shapes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// Hexagons have a vertex at the top and form horizontal rows.
+    #[default]
+    Pointy,
+    /// Hexagons have a side at the top and form vertical columns.
+    Flat,
+}
+
+/// Shape of a hexagonal map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// Rows and columns of hexagons, with every other row (or column for the flat orientation)
+    /// shifted by half a hexagon.
+    Rectangle { rows: usize, columns: usize },
+    /// Hexagons within the `radius` from the `(0, 0)` hexagon.
+    Hexagon { radius: usize },
+    /// Parallelogram spanning `0..width` along the `q` axis and `0..height` along the `r` axis.
+    Rhombus { width: usize, height: usize },
+}
+
+impl Shape {
+    /// Return coordinates of all hexagons in the shape.
+    fn cells(self, orientation: Orientation) -> Vec<Axial> {
+        match self {
+            Shape::Rectangle { rows, columns } => {
+                let (rows, columns) = (rows as isize, columns as isize);
+                match orientation {
+                    Orientation::Pointy => (0..rows)
+                        .flat_map(|r| (0..columns).map(move |x| Axial(x - r.div_euclid(2), r)))
+                        .collect(),
+                    Orientation::Flat => (0..columns)
+                        .flat_map(|q| (0..rows).map(move |y| Axial(q, y - q.div_euclid(2))))
+                        .collect(),
+                }
+            }
+            Shape::Hexagon { radius } => Axial(0, 0).spiral(radius).collect(),
+            Shape::Rhombus { width, height } => (0..height as isize)
+                .flat_map(|r| (0..width as isize).map(move |q| Axial(q, r)))
+                .collect(),
+        }
+    }
+}
+
+/// A graph of hexagonal cells, where every cell is connected to up to six cells sharing
+/// a side with it. Cells are addressed with [`Axial`] coordinates, and the set of cells is
+/// defined by a [`Shape`]. Just like in [`Grid`](super::Grid), edges are not stored.
+pub struct HexGrid<I = Counter> {
+    orientation: Orientation,
+    cells: HashMap<Axial, I>,
+    coords: HashMap<I, Axial>,
+}
+
+/// Construction interface.
+impl<I: Unique + Index> HexGrid<I> {
+    /// Create a new hexagonal grid with the given shape.
+    pub fn new(shape: Shape, orientation: Orientation) -> Self {
+        Self::with_inspector(shape, orientation, |_, _| ())
+    }
+
+    /// Create a new hexagonal grid with the given shape, calling `inspector` function for
+    /// each created vertex with its index and coordinates.
+    pub fn with_inspector(
+        shape: Shape,
+        orientation: Orientation,
+        mut inspector: impl FnMut(I, Axial),
+    ) -> Self {
+        let mut cells = HashMap::new();
+        let mut coords = HashMap::new();
+        for hex in shape.cells(orientation) {
+            let id = Unique::generate();
+            cells.insert(hex, id);
+            coords.insert(id, hex);
+            inspector(id, hex);
+        }
+        Self {
+            orientation,
+            cells,
+            coords,
+        }
+    }
+}
+
+/// Coordinate-related methods.
+impl<I: Index> HexGrid<I> {
+    /// Return the orientation of the hexagons.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Return the index of a node at the given coordinates.
+    pub fn at(&self, hex: Axial) -> Option<I> {
+        self.cells.get(&hex).copied()
+    }
+
+    /// Return the coordinates of the node by the given index.
+    pub fn coords_of(&self, id: I) -> Option<Axial> {
+        self.coords.get(&id).copied()
+    }
+
+    /// Return the coordinates of all neighbors of a hexagon that belong to the grid.
+    pub fn neighbors_of(&self, hex: Axial) -> impl Iterator<Item = Axial> + '_ {
+        hex.neighbors()
+            .filter(move |neighbor| self.cells.contains_key(neighbor))
+    }
+
+    /// Return indices of vertices adjacent to a vertex, or `None` if there is no such vertex.
+    fn adjacent_to(&self, id: I) -> Option<impl Iterator<Item = I> + '_> {
+        let hex = self.coords_of(id)?;
+        Some(hex.neighbors().filter_map(|hex| self.at(hex)))
+    }
+
+    /// Return the number of steps between two vertices.
+    pub fn distance(&self, a: I, b: I) -> Option<usize> {
+        Some(self.coords_of(a)?.distance(self.coords_of(b)?))
+    }
+
+    /// Iterate over vertices at exactly the given distance from the `center`.
+    pub fn ring(&self, center: Axial, radius: usize) -> impl Iterator<Item = I> + '_ {
+        center.ring(radius).filter_map(|hex| self.at(hex))
+    }
+
+    /// Iterate over vertices within the given distance from the `center`, ring by ring.
+    pub fn spiral(&self, center: Axial, radius: usize) -> impl Iterator<Item = I> + '_ {
+        center.spiral(radius).filter_map(|hex| self.at(hex))
+    }
+
+    /// Return the pixel position of the center of a hexagon, given the distance from the center
+    /// to the vertices of hexagons.
+    pub fn center_of(&self, Axial(q, r): Axial, size: f64) -> (f64, f64) {
+        let (q, r) = (q as f64, r as f64);
+        let root = 3f64.sqrt();
+        match self.orientation {
+            Orientation::Pointy => (size * root * (q + r / 2.0), size * 1.5 * r),
+            Orientation::Flat => (size * 1.5 * q, size * root * (r + q / 2.0)),
+        }
+    }
+}
+
+// Vertex and edge providers
+
+impl<I: Index> VertexProvider<I> for HexGrid<I> {
+    type Vertices<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = I>;
+
+    fn order(&self) -> usize {
+        self.coords.len()
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        Vertices { grid: self }
+    }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        Some(self.neighbors_of(self.coords_of(item)?).count())
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+}
+
+impl<I: Index> EdgeProvider<I> for HexGrid<I> {
+    type Edge = Edge<I>;
+    type Edges<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = Self::Edge>;
+
+    fn size(&self) -> usize {
+        self.edges().iter().count()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        Edges { grid: self }
+    }
+}
+
+// Vertex topology
+
+struct Vertices<'a, I> {
+    grid: &'a HexGrid<I>,
+}
+
+impl<'a, I: Index> Topology for Vertices<'a, I> {
+    type Item = I;
+    type BuildHasher = RandomState;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        self.grid.coords.keys().copied()
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        self.grid.adjacent_to(item)
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        self.grid.coords.contains_key(&item)
+    }
+}
+
+// Edge topology
+
+struct Edges<'a, I> {
+    grid: &'a HexGrid<I>,
+}
+
+impl<'a, I: Index> Topology for Edges<'a, I> {
+    type Item = Edge<I>;
+    type BuildHasher = UnorderedBuildHasher;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        // Every edge is produced once, by following the first three directions only.
+        self.grid.cells.iter().flat_map(move |(hex, id)| {
+            DIRECTIONS[..3]
+                .iter()
+                .filter_map(move |direction| self.grid.at(hex.step(*direction, 1)))
+                .map(move |neighbor| Edge::new(*id, neighbor))
+        })
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        let Edge(UnorderedPair(a, b)) = item;
+        let a_neighbors = outbound_edges(a, b, self.grid.adjacent_to(a)?);
+        let b_neighbors = outbound_edges(b, a, self.grid.adjacent_to(b)?);
+        Some(a_neighbors.chain(b_neighbors))
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        let UnorderedPair(a, b) = item.0;
+        self.grid.distance(a, b) == Some(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cube_coordinates_round_trip() {
+        let hex = Axial(2, -3);
+        assert_eq!(hex.cube(), (2, -3, 1));
+        assert_eq!(Axial::from_cube(2, -3, 1), Some(hex));
+        assert_eq!(Axial::from_cube(2, -3, 0), None);
+    }
+
+    #[test]
+    fn opposite_directions_cancel_out() {
+        for k in 0..3 {
+            let Axial(q, r) = DIRECTIONS[k];
+            assert_eq!(DIRECTIONS[k + 3], Axial(-q, -r));
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! This module defines an N-dimensional lattice graph and its related structures.
+
+use super::edge::outbound_edges;
+use crate::index::*;
+use crate::providers::*;
+use crate::topology::*;
+use crate::utils::{UnorderedBuildHasher, UnorderedPair};
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+pub use super::edge::{Edge, EdgeSet};
+
+/// An N-dimensional generalization of [`Grid`](super::Grid), arranging its nodes in a box with
+/// the given length along each axis. Every vertex is connected to the vertices that differ by one
+/// in a single coordinate, so it has at most `2 * N` neighbors. `Lattice<I, 3>` is a voxel grid.
+///
+/// Each axis can wrap around, making the first and the last layers along it neighbors. Wrapping
+/// never creates self-loops or parallel edges: an axis of length one has no edges, and an axis of
+/// length two has a single edge between its layers.
+pub struct Lattice<I, const N: usize> {
+    dims: [usize; N],
+    wrapping: [bool; N],
+    cells: Vec<I>,
+    coords: HashMap<I, [usize; N]>,
+}
+
+/// Construction interface.
+impl<I: Unique + Index, const N: usize> Lattice<I, N> {
+    /// Create a new lattice with the given lengths of axes.
+    pub fn new(dims: [usize; N]) -> Self {
+        Self::with_inspector(dims, |_, _| ())
+    }
+
+    /// Create a new lattice with the given lengths of axes, calling `inspector` function for
+    /// each created vertex with its index and coordinates. Vertices are created in row-major
+    /// order, where the last coordinate changes the fastest.
+    pub fn with_inspector(dims: [usize; N], mut inspector: impl FnMut(I, [usize; N])) -> Self {
+        let len = dims.iter().product();
+        let mut cells = Vec::with_capacity(len);
+        let mut coords = HashMap::with_capacity(len);
+        for position in 0..len {
+            let id = Unique::generate();
+            let point = unflatten(&dims, position);
+            cells.push(id);
+            coords.insert(id, point);
+            inspector(id, point);
+        }
+        Self {
+            dims,
+            wrapping: [false; N],
+            cells,
+            coords,
+        }
+    }
+}
+
+/// Configuration interface.
+impl<I, const N: usize> Lattice<I, N> {
+    /// Choose which axes wrap around.
+    pub fn with_wrapping(mut self, wrapping: [bool; N]) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Return the lengths of the axes.
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+}
+
+/// Lattice-specific interface provides several coordinate-related methods.
+impl<I: Index, const N: usize> Lattice<I, N> {
+    /// Return the index of a node at the given coordinates.
+    pub fn at(&self, point: [usize; N]) -> Option<I> {
+        let inside = point.iter().zip(&self.dims).all(|(x, len)| x < len);
+        inside.then(|| self.cells[flatten(&self.dims, &point)])
+    }
+
+    /// Return the coordinates of the node by the given index.
+    pub fn coords_of(&self, id: I) -> Option<[usize; N]> {
+        self.coords.get(&id).copied()
+    }
+
+    /// Return the coordinates of all neighbors to a vertex at the given coordinates.
+    pub fn neighbors_of(&self, point: [usize; N]) -> impl Iterator<Item = [usize; N]> + '_ {
+        (0..N).flat_map(move |axis| {
+            let forward = self.step(point, axis, true);
+            // On a wrapped axis of length two, both steps lead to the same neighbor.
+            let backward = self
+                .step(point, axis, false)
+                .filter(|backward| Some(*backward) != forward);
+            forward.into_iter().chain(backward)
+        })
+    }
+
+    /// Return indices of vertices adjacent to a vertex, or `None` if there is no such vertex.
+    fn adjacent_to(&self, id: I) -> Option<impl Iterator<Item = I> + '_> {
+        let point = self.coords_of(id)?;
+        Some(
+            self.neighbors_of(point)
+                .map(|point| self.at(point).unwrap()),
+        )
+    }
+
+    /// Return the neighbor one step forward or backward along the axis, if there is one.
+    fn step(&self, mut point: [usize; N], axis: usize, forward: bool) -> Option<[usize; N]> {
+        let len = self.dims[axis];
+        let x = point[axis];
+        point[axis] = match (forward, self.wrapping[axis]) {
+            (true, _) if x + 1 < len => x + 1,
+            (false, _) if x > 0 => x - 1,
+            (true, true) if len > 1 => 0,
+            (false, true) if len > 1 => len - 1,
+            _ => return None,
+        };
+        Some(point)
+    }
+
+    /// Traverse vertices in the lexicographic order of their coordinates, where axes are compared
+    /// in the given `order`. The `[0, 1, .., N - 1]` order is the row-major traversal, and the
+    /// reversed one is the column-major traversal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not a permutation of axes.
+    pub fn traverse_by_axes(&self, order: [usize; N]) -> impl Iterator<Item = I> + '_ {
+        let mut seen = [false; N];
+        for axis in order {
+            assert!(axis < N && !seen[axis], "axis order must be a permutation");
+            seen[axis] = true;
+        }
+        (0..self.cells.len()).map(move |mut position| {
+            let mut point = [0; N];
+            for &axis in order.iter().rev() {
+                point[axis] = position % self.dims[axis];
+                position /= self.dims[axis];
+            }
+            self.cells[flatten(&self.dims, &point)]
+        })
+    }
+}
+
+fn flatten<const N: usize>(dims: &[usize; N], point: &[usize; N]) -> usize {
+    point
+        .iter()
+        .zip(dims)
+        .fold(0, |position, (x, len)| position * len + x)
+}
+
+fn unflatten<const N: usize>(dims: &[usize; N], mut position: usize) -> [usize; N] {
+    let mut point = [0; N];
+    for axis in (0..N).rev() {
+        point[axis] = position % dims[axis];
+        position /= dims[axis];
+    }
+    point
+}
+
+// Vertex and edge providers
+
+impl<I: Index, const N: usize> VertexProvider<I> for Lattice<I, N> {
+    type Vertices<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = I>;
+
+    fn order(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        Vertices { lattice: self }
+    }
+
+    fn degree(&self, item: I) -> Option<usize> {
+        Some(self.neighbors_of(self.coords_of(item)?).count())
+    }
+
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+
+    fn in_degree(&self, item: I) -> Option<usize> {
+        self.degree(item)
+    }
+}
+
+impl<I: Index, const N: usize> EdgeProvider<I> for Lattice<I, N> {
+    type Edge = Edge<I>;
+    type Edges<'a>
+    where
+        Self: 'a,
+    = impl Topology<Item = Self::Edge>;
+
+    fn size(&self) -> usize {
+        (0..N)
+            .map(|axis| {
+                let len = self.dims[axis];
+                let layers = self.cells.len().checked_div(len).unwrap_or(0);
+                let edges = match self.wrapping[axis] && len > 2 {
+                    true => len,
+                    false => len.saturating_sub(1),
+                };
+                layers * edges
+            })
+            .sum()
+    }
+
+    fn edges(&self) -> Self::Edges<'_> {
+        Edges { lattice: self }
+    }
+}
+
+// Vertex topology
+
+struct Vertices<'a, I, const N: usize> {
+    lattice: &'a Lattice<I, N>,
+}
+
+impl<'a, I: Index, const N: usize> Topology for Vertices<'a, I, N> {
+    type Item = I;
+    type BuildHasher = RandomState;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        self.lattice.cells.iter().copied()
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        self.lattice.adjacent_to(item)
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        self.lattice.coords.contains_key(&item)
+    }
+}
+
+// Edge topology
+
+struct Edges<'a, I, const N: usize> {
+    lattice: &'a Lattice<I, N>,
+}
+
+impl<'a, I: Index, const N: usize> Topology for Edges<'a, I, N> {
+    type Item = Edge<I>;
+    type BuildHasher = UnorderedBuildHasher;
+    type ItemIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+    type AdjacentIter<'b>
+    where
+        Self: 'b,
+    = impl Iterator<Item = Self::Item>;
+
+    fn iter(&self) -> Self::ItemIter<'_> {
+        // Every edge is produced once, by stepping forward from its first end. Wrapping steps
+        // of axes of length two repeat the edge stepping forward from the other end.
+        let lattice = self.lattice;
+        (0..lattice.cells.len()).flat_map(move |position| {
+            let point = unflatten(&lattice.dims, position);
+            (0..N).filter_map(move |axis| {
+                let wraps = point[axis] + 1 == lattice.dims[axis];
+                if wraps && lattice.dims[axis] <= 2 {
+                    return None;
+                }
+                let neighbor = lattice.step(point, axis, true)?;
+                Some(Edge::new(lattice.cells[position], lattice.at(neighbor)?))
+            })
+        })
+    }
+
+    fn adjacent_to(&self, item: Self::Item) -> Option<Self::AdjacentIter<'_>> {
+        let Edge(UnorderedPair(a, b)) = item;
+        let a_neighbors = outbound_edges(a, b, self.lattice.adjacent_to(a)?);
+        let b_neighbors = outbound_edges(b, a, self.lattice.adjacent_to(b)?);
+        Some(a_neighbors.chain(b_neighbors))
+    }
+
+    fn contains(&self, item: Self::Item) -> bool {
+        let UnorderedPair(a, b) = item.0;
+        match (self.lattice.coords_of(a), self.lattice.coords_of(b)) {
+            (Some(a), Some(b)) => self.lattice.neighbors_of(a).any(|point| point == b),
+            _ => false,
+        }
+    }
+}
@@ -7,7 +7,7 @@ static COUNTER: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
 /// `Unique` constraints. In generates new usize-based integers using atomic add operation.
 /// There is no guarantee that indices will be sequential or, in fact, that they will be
 /// monotonically increasing.
-#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
 pub struct Counter(usize);
 
 impl Unique for Counter {
@@ -0,0 +1,13 @@
+//! This module is a home for graph isomorphism algorithms. Two graphs are isomorphic if there is
+//! a bijection between their vertices that preserves adjacency. The algorithms only look at the
+//! vertex topologies, so graphs of different types, like an [`AdjacencyList`] and a [`Grid`], can
+//! be compared with each other, and the resulting mappings translate between their indices.
+//!
+//! [`AdjacencyList`]: crate::graphs::AdjacencyList
+//! [`Grid`]: crate::graphs::Grid
+
+mod vf2;
+mod weisfeiler_lehman;
+
+pub use vf2::*;
+pub use weisfeiler_lehman::*;
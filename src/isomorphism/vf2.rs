@@ -0,0 +1,401 @@
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::{HashMap, HashSet};
+
+const NONE: usize = usize::MAX;
+
+/// Check whether graphs `a` and `b` are isomorphic using the VF2 algorithm, returning a mapping
+/// from vertices of `a` to vertices of `b` if they are. Adjacency is treated as directed, so
+/// directed graphs are matched with respect to the edge direction.
+pub fn is_isomorphic<IA, IB, GA, GB>(a: &GA, b: &GB) -> Option<HashMap<IA, IB>>
+where
+    IA: Index,
+    IB: Index,
+    GA: VertexProvider<IA>,
+    GB: VertexProvider<IB>,
+{
+    is_isomorphic_by(a, b, |_, _| true, |_, _| true)
+}
+
+/// Check whether graphs `a` and `b` are isomorphic with a mapping that only pairs compatible
+/// vertices and edges. The `vertex_match` predicate is called with a vertex of `a` and a vertex
+/// of `b`, and `edge_match` is called with corresponding edges given as pairs of their ends.
+/// This allows label-aware matching where labels are stored by the client.
+pub fn is_isomorphic_by<IA, IB, GA, GB, VM, EM>(
+    a: &GA,
+    b: &GB,
+    vertex_match: VM,
+    edge_match: EM,
+) -> Option<HashMap<IA, IB>>
+where
+    IA: Index,
+    IB: Index,
+    GA: VertexProvider<IA>,
+    GB: VertexProvider<IB>,
+    VM: Fn(IA, IB) -> bool,
+    EM: Fn((IA, IA), (IB, IB)) -> bool,
+{
+    let a = Graph::new(Dense::new(&a.vertices()));
+    let b = Graph::new(Dense::new(&b.vertices()));
+    if a.len() != b.len() || a.size() != b.size() || a.degrees() != b.degrees() {
+        return None;
+    }
+    let mut matcher = Matcher::new(a, b, Mode::Isomorphism, vertex_match, edge_match);
+    matcher.next_mapping()
+}
+
+/// Lazily enumerate all mappings of the `pattern` onto induced subgraphs of the `target` using
+/// the VF2 algorithm. Each mapping is injective, maps adjacent vertices of the pattern to adjacent
+/// vertices of the target, and non-adjacent ones to non-adjacent. Automorphisms of the pattern
+/// produce separate mappings onto the same subgraph. Both graphs are read once when this function
+/// is called, so the iterator does not borrow them.
+pub fn subgraph_matches<IP, IT, GP, GT>(
+    pattern: &GP,
+    target: &GT,
+) -> impl Iterator<Item = HashMap<IP, IT>>
+where
+    IP: Index,
+    IT: Index,
+    GP: VertexProvider<IP>,
+    GT: VertexProvider<IT>,
+{
+    subgraph_matches_by(pattern, target, |_, _| true, |_, _| true)
+}
+
+/// Lazily enumerate all mappings of the `pattern` onto induced subgraphs of the `target` that only
+/// pair compatible vertices and edges. See [`subgraph_matches`] and [`is_isomorphic_by`] for
+/// details.
+pub fn subgraph_matches_by<IP, IT, GP, GT, VM, EM>(
+    pattern: &GP,
+    target: &GT,
+    vertex_match: VM,
+    edge_match: EM,
+) -> impl Iterator<Item = HashMap<IP, IT>>
+where
+    IP: Index,
+    IT: Index,
+    GP: VertexProvider<IP>,
+    GT: VertexProvider<IT>,
+    VM: Fn(IP, IT) -> bool,
+    EM: Fn((IP, IP), (IT, IT)) -> bool,
+{
+    let pattern = Graph::new(Dense::new(&pattern.vertices()));
+    let target = Graph::new(Dense::new(&target.vertices()));
+    let mut matcher = Matcher::new(pattern, target, Mode::Subgraph, vertex_match, edge_match);
+    std::iter::from_fn(move || matcher.next_mapping())
+}
+
+/// Directed graph with dense indices and constant-time adjacency checks.
+struct Graph<I> {
+    ids: Vec<I>,
+    outbound: Vec<HashSet<usize>>,
+    inbound: Vec<HashSet<usize>>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl<I> Graph<I> {
+    fn new(dense: Dense<I>) -> Self {
+        let n = dense.len();
+        let mut inbound = vec![HashSet::new(); n];
+        for (v, adjacent) in dense.adjacency.iter().enumerate() {
+            for &u in adjacent {
+                inbound[u].insert(v);
+            }
+        }
+        let outbound = dense
+            .adjacency
+            .into_iter()
+            .map(|adjacent| adjacent.into_iter().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+        let neighbors = outbound
+            .iter()
+            .zip(&inbound)
+            .map(|(outbound, inbound)| outbound.union(inbound).copied().collect())
+            .collect();
+        Self {
+            ids: dense.ids,
+            outbound,
+            inbound,
+            neighbors,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn size(&self) -> usize {
+        self.outbound.iter().map(HashSet::len).sum()
+    }
+
+    fn degree(&self, v: usize) -> (usize, usize) {
+        (self.outbound[v].len(), self.inbound[v].len())
+    }
+
+    /// Sorted sequence of `(out, in)` degrees.
+    fn degrees(&self) -> Vec<(usize, usize)> {
+        let mut degrees = (0..self.len()).map(|v| self.degree(v)).collect::<Vec<_>>();
+        degrees.sort_unstable();
+        degrees
+    }
+
+    /// Order vertices so that each vertex has as many previously ordered neighbors as possible,
+    /// which lets the search detect mismatches early. Ties are broken by the larger degree.
+    fn matching_order(&self) -> Vec<usize> {
+        let n = self.len();
+        let mut ordered = vec![false; n];
+        let mut connections = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        for _ in 0..n {
+            let v = (0..n)
+                .filter(|v| !ordered[*v])
+                .max_by_key(|v| (connections[*v], self.neighbors[*v].len(), NONE - v))
+                .unwrap();
+            ordered[v] = true;
+            order.push(v);
+            for &u in &self.neighbors[v] {
+                connections[u] += 1;
+            }
+        }
+        order
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    Isomorphism,
+    Subgraph,
+}
+
+/// A level of the search: candidates for the vertex of the first graph at this depth.
+struct Frame {
+    candidates: Vec<usize>,
+    next: usize,
+}
+
+/// VF2 state with an explicit stack, so the search can be suspended after each mapping.
+struct Matcher<IA, IB, VM, EM> {
+    a: Graph<IA>,
+    b: Graph<IB>,
+    mode: Mode,
+    vertex_match: VM,
+    edge_match: EM,
+    order: Vec<usize>,
+    core_a: Vec<usize>,
+    core_b: Vec<usize>,
+    /// Numbers of mapped neighbors of each vertex. Unmapped vertices with mapped neighbors form
+    /// the terminal sets of VF2.
+    terminal_a: Vec<usize>,
+    terminal_b: Vec<usize>,
+    stack: Vec<Frame>,
+    started: bool,
+}
+
+impl<IA, IB, VM, EM> Matcher<IA, IB, VM, EM>
+where
+    IA: Index,
+    IB: Index,
+    VM: Fn(IA, IB) -> bool,
+    EM: Fn((IA, IA), (IB, IB)) -> bool,
+{
+    fn new(a: Graph<IA>, b: Graph<IB>, mode: Mode, vertex_match: VM, edge_match: EM) -> Self {
+        let order = a.matching_order();
+        Self {
+            core_a: vec![NONE; a.len()],
+            core_b: vec![NONE; b.len()],
+            terminal_a: vec![0; a.len()],
+            terminal_b: vec![0; b.len()],
+            a,
+            b,
+            mode,
+            vertex_match,
+            edge_match,
+            order,
+            stack: Vec::new(),
+            started: false,
+        }
+    }
+
+    fn next_mapping(&mut self) -> Option<HashMap<IA, IB>> {
+        if !self.started {
+            self.started = true;
+            if self.a.len() > self.b.len() {
+                return None;
+            }
+            if self.a.len() == 0 {
+                return Some(HashMap::new());
+            }
+            let frame = self.frame(0);
+            self.stack.push(frame);
+        }
+
+        while let Some(depth) = self.stack.len().checked_sub(1) {
+            let v = self.order[depth];
+            if self.core_a[v] != NONE {
+                let u = self.core_a[v];
+                self.unmap(v, u);
+            }
+
+            let candidate = loop {
+                let frame = self.stack.last_mut().unwrap();
+                match frame.candidates.get(frame.next) {
+                    None => break None,
+                    Some(&u) => {
+                        frame.next += 1;
+                        if self.feasible(v, u) {
+                            break Some(u);
+                        }
+                    }
+                }
+            };
+
+            match candidate {
+                None => {
+                    self.stack.pop();
+                }
+                Some(u) => {
+                    self.map(v, u);
+                    if depth + 1 == self.a.len() {
+                        return Some(self.mapping());
+                    }
+                    let frame = self.frame(depth + 1);
+                    self.stack.push(frame);
+                }
+            }
+        }
+        None
+    }
+
+    /// Collect candidates for the vertex at the given `depth` of the matching order. If the vertex
+    /// has a mapped neighbor, only neighbors of its image can be candidates.
+    fn frame(&self, depth: usize) -> Frame {
+        let v = self.order[depth];
+        let anchor = self.a.neighbors[v]
+            .iter()
+            .find(|w| self.core_a[**w] != NONE)
+            .map(|w| self.core_a[*w]);
+        let candidates = match anchor {
+            Some(image) => self.b.neighbors[image]
+                .iter()
+                .copied()
+                .filter(|u| self.core_b[*u] == NONE)
+                .collect(),
+            None => (0..self.b.len())
+                .filter(|u| self.core_b[*u] == NONE)
+                .collect(),
+        };
+        Frame {
+            candidates,
+            next: 0,
+        }
+    }
+
+    fn feasible(&self, v: usize, u: usize) -> bool {
+        let (out_a, in_a) = self.a.degree(v);
+        let (out_b, in_b) = self.b.degree(u);
+        let degrees_fit = match self.mode {
+            Mode::Isomorphism => out_a == out_b && in_a == in_b,
+            Mode::Subgraph => out_a <= out_b && in_a <= in_b,
+        };
+        if !degrees_fit || !(self.vertex_match)(self.a.ids[v], self.b.ids[u]) {
+            return false;
+        }
+
+        // Mapped neighbors must correspond in both directions, which also makes the match induced.
+        for &w in &self.a.neighbors[v] {
+            let image = self.core_a[w];
+            if image == NONE {
+                continue;
+            }
+            if self.a.outbound[v].contains(&w) != self.b.outbound[u].contains(&image)
+                || self.a.inbound[v].contains(&w) != self.b.inbound[u].contains(&image)
+            {
+                return false;
+            }
+        }
+        for &w in &self.b.neighbors[u] {
+            let preimage = self.core_b[w];
+            if preimage != NONE && !self.a.neighbors[v].contains(&preimage) {
+                return false;
+            }
+        }
+        if !self.edges_match(v, u) {
+            return false;
+        }
+
+        // Lookahead: unmapped neighbors in the terminal sets and outside of them must be
+        // matchable to the unmapped neighbors of the candidate.
+        let (terminal_a, new_a) =
+            self.lookahead(&self.a.neighbors[v], &self.core_a, &self.terminal_a);
+        let (terminal_b, new_b) =
+            self.lookahead(&self.b.neighbors[u], &self.core_b, &self.terminal_b);
+        match self.mode {
+            Mode::Isomorphism => terminal_a == terminal_b && new_a == new_b,
+            Mode::Subgraph => terminal_a <= terminal_b && new_a <= new_b,
+        }
+    }
+
+    fn edges_match(&self, v: usize, u: usize) -> bool {
+        let (id_v, id_u) = (self.a.ids[v], self.b.ids[u]);
+        let outbound = self.a.outbound[v]
+            .iter()
+            .filter(|w| self.core_a[**w] != NONE);
+        for &w in outbound {
+            let (id_w, id_image) = (self.a.ids[w], self.b.ids[self.core_a[w]]);
+            if !(self.edge_match)((id_v, id_w), (id_u, id_image)) {
+                return false;
+            }
+        }
+        let inbound = self.a.inbound[v]
+            .iter()
+            .filter(|w| self.core_a[**w] != NONE);
+        for &w in inbound {
+            let (id_w, id_image) = (self.a.ids[w], self.b.ids[self.core_a[w]]);
+            if !(self.edge_match)((id_w, id_v), (id_image, id_u)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Count unmapped `neighbors` inside and outside of the terminal set.
+    fn lookahead(&self, neighbors: &[usize], core: &[usize], terminal: &[usize]) -> (usize, usize) {
+        let unmapped = neighbors.iter().filter(|w| core[**w] == NONE);
+        unmapped.fold((0, 0), |(inside, outside), w| match terminal[*w] {
+            0 => (inside, outside + 1),
+            _ => (inside + 1, outside),
+        })
+    }
+
+    fn map(&mut self, v: usize, u: usize) {
+        self.core_a[v] = u;
+        self.core_b[u] = v;
+        self.a.neighbors[v]
+            .iter()
+            .for_each(|w| self.terminal_a[*w] += 1);
+        self.b.neighbors[u]
+            .iter()
+            .for_each(|w| self.terminal_b[*w] += 1);
+    }
+
+    fn unmap(&mut self, v: usize, u: usize) {
+        self.core_a[v] = NONE;
+        self.core_b[u] = NONE;
+        self.a.neighbors[v]
+            .iter()
+            .for_each(|w| self.terminal_a[*w] -= 1);
+        self.b.neighbors[u]
+            .iter()
+            .for_each(|w| self.terminal_b[*w] -= 1);
+    }
+
+    fn mapping(&self) -> HashMap<IA, IB> {
+        self.core_a
+            .iter()
+            .enumerate()
+            .map(|(v, u)| (self.a.ids[v], self.b.ids[*u]))
+            .collect()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::HashMap;
+
+/// Result of the Weisfeiler-Lehman refinement.
+#[derive(Clone, Debug)]
+pub struct WlHash<I> {
+    /// Fingerprint of the whole graph.
+    pub hash: u64,
+    /// Refined label of each vertex after the last iteration.
+    pub labels: HashMap<I, u64>,
+}
+
+/// Compute a structural fingerprint of the graph with the Weisfeiler-Lehman refinement. Each
+/// vertex starts with `initial_label` of its index, and on each of the `iterations` its label is
+/// replaced with a hash of the label together with the sorted labels of its outbound and inbound
+/// neighbors. The graph hash combines the sorted labels of all iterations.
+///
+/// Isomorphic graphs always get equal hashes, as long as `initial_label` agrees on corresponding
+/// vertices. The hash does not depend on the index values or on the iteration order of the graph,
+/// and it is stable between runs. Different hashes prove that graphs are not isomorphic, while
+/// equal hashes need confirmation, for example with [`is_isomorphic`](super::is_isomorphic).
+/// Self-loops are ignored.
+pub fn wl_hash<I, G, L>(graph: &G, iterations: usize, initial_label: L) -> WlHash<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    L: Fn(I) -> u64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let mut inbound = vec![Vec::new(); dense.len()];
+    for (v, adjacent) in dense.adjacency.iter().enumerate() {
+        for &u in adjacent {
+            inbound[u].push(v);
+        }
+    }
+
+    let mut labels = dense
+        .ids
+        .iter()
+        .map(|id| initial_label(*id))
+        .collect::<Vec<_>>();
+    let mut hash = combine(0, dense.len() as u64);
+    hash = combine(hash, multiset_hash(labels.iter().copied()));
+    for _ in 0..iterations {
+        labels = (0..dense.len())
+            .map(|v| {
+                let outbound = multiset_hash(dense.adjacency[v].iter().map(|u| labels[*u]));
+                let inbound = multiset_hash(inbound[v].iter().map(|u| labels[*u]));
+                combine(combine(labels[v], outbound), inbound)
+            })
+            .collect();
+        hash = combine(hash, multiset_hash(labels.iter().copied()));
+    }
+
+    WlHash {
+        hash,
+        labels: dense.ids.into_iter().zip(labels).collect(),
+    }
+}
+
+/// Seed of the hashes of label multisets.
+const SEED: u64 = 0x5157_4c48_4153_4800;
+
+/// Hash a multiset of labels.
+fn multiset_hash(labels: impl Iterator<Item = u64>) -> u64 {
+    let mut multiset = labels.collect::<Vec<_>>();
+    multiset.sort_unstable();
+    multiset.into_iter().fold(SEED, combine)
+}
+
+/// Order-dependent combination of two hashes, based on the SplitMix64 finalizer.
+fn combine(seed: u64, value: u64) -> u64 {
+    let mut x = seed
+        .rotate_left(23)
+        .wrapping_add(value)
+        .wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
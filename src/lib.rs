@@ -36,10 +36,18 @@
 #![feature(generic_associated_types)]
 #![feature(mixed_integer_ops)]
 
+pub mod centrality;
+pub mod cliques;
+pub mod coloring;
+pub mod community;
 pub mod construct;
 pub mod graphs;
 pub mod index;
+pub mod isomorphism;
 pub mod marker;
+pub mod matching;
+pub mod metrics;
+pub mod paths;
 pub mod providers;
 pub mod topology;
 pub mod traversal;
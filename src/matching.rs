@@ -0,0 +1,79 @@
+//! This module is a home for matching algorithms. A matching is a set of edges without common
+//! vertices, and most of the algorithms here look for a matching with the largest possible
+//! number of edges. Graph-based algorithms operate on the vertex topology of a graph and treat it
+//! as undirected, so the adjacency relation is expected to be symmetric. The weighted assignment
+//! problem is solved directly on two sets of items and a cost function.
+
+use crate::index::Index;
+use crate::utils::UnorderedPair;
+
+use std::collections::{HashMap, HashSet};
+
+mod bipartite;
+mod blossom;
+mod greedy;
+mod hungarian;
+
+pub use bipartite::*;
+pub use blossom::*;
+pub use greedy::*;
+pub use hungarian::*;
+
+/// A set of matched edges, stored as a mapping from each matched vertex to its mate.
+#[derive(Clone, Debug)]
+pub struct Matching<I> {
+    mates: HashMap<I, I>,
+}
+
+impl<I> Default for Matching<I> {
+    fn default() -> Self {
+        Self {
+            mates: HashMap::new(),
+        }
+    }
+}
+
+impl<I: Index> Matching<I> {
+    /// Create an empty matching.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the vertex matched with `id`, if any.
+    pub fn mate_of(&self, id: I) -> Option<I> {
+        self.mates.get(&id).copied()
+    }
+
+    /// Check if the given vertex is covered by the matching.
+    pub fn is_matched(&self, id: I) -> bool {
+        self.mates.contains_key(&id)
+    }
+
+    /// Return the number of matched edges.
+    pub fn len(&self) -> usize {
+        self.mates.len() / 2
+    }
+
+    /// Check if the matching contains no edges.
+    pub fn is_empty(&self) -> bool {
+        self.mates.is_empty()
+    }
+
+    /// Iterate through the matched edges. Each edge is visited exactly once.
+    pub fn edges(&self) -> impl Iterator<Item = UnorderedPair<I>> + '_ {
+        let mut visited = HashSet::new();
+        self.mates.iter().filter_map(move |(a, b)| {
+            if visited.contains(b) {
+                return None;
+            }
+            visited.insert(*a);
+            Some(UnorderedPair(*a, *b))
+        })
+    }
+
+    /// Add an edge to the matching. Both vertices must be unmatched.
+    pub(crate) fn insert(&mut self, a: I, b: I) {
+        self.mates.insert(a, b);
+        self.mates.insert(b, a);
+    }
+}
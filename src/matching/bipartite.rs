@@ -0,0 +1,233 @@
+use super::Matching;
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::topology::Topology;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const UNREACHED: usize = usize::MAX;
+
+/// Find the maximum-cardinality matching in a bipartite graph using the Hopcroft-Karp algorithm.
+/// The `left_side` enumerates the vertices of one part of the graph, and all their neighbors are
+/// considered to form the other part. Edges between two vertices of the left part are ignored.
+/// The algorithm runs in `O(E * sqrt(V))` time.
+pub fn hopcroft_karp<I, G>(graph: &G, left_side: impl IntoIterator<Item = I>) -> Matching<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let bipartite = Bipartite::new(graph, left_side);
+    let (pair_left, _) = bipartite.maximum_matching();
+    let mut matching = Matching::new();
+    for (u, v) in pair_left.iter().enumerate() {
+        if let Some(v) = v {
+            matching.insert(bipartite.left[u], bipartite.right[*v]);
+        }
+    }
+    matching
+}
+
+/// Find the minimum vertex cover of a bipartite graph. By König's theorem, the size of the
+/// minimum vertex cover equals the size of the maximum matching, and the cover itself is
+/// constructed from the alternating paths starting at unmatched vertices of the left part.
+/// See [`hopcroft_karp`] for the meaning of the `left_side`.
+pub fn minimum_vertex_cover<I, G>(graph: &G, left_side: impl IntoIterator<Item = I>) -> HashSet<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let bipartite = Bipartite::new(graph, left_side);
+    let (pair_left, pair_right) = bipartite.maximum_matching();
+
+    let mut visited_left = vec![false; bipartite.left.len()];
+    let mut visited_right = vec![false; bipartite.right.len()];
+    let mut queue = VecDeque::new();
+    for (u, pair) in pair_left.iter().enumerate() {
+        if pair.is_none() {
+            visited_left[u] = true;
+            queue.push_back(u);
+        }
+    }
+    while let Some(u) = queue.pop_front() {
+        for &v in &bipartite.adjacency[u] {
+            if visited_right[v] || pair_left[u] == Some(v) {
+                continue;
+            }
+            visited_right[v] = true;
+            if let Some(w) = pair_right[v] {
+                if !visited_left[w] {
+                    visited_left[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+
+    let left = bipartite
+        .left
+        .iter()
+        .zip(visited_left)
+        .filter(|(_, visited)| !visited);
+    let right = bipartite
+        .right
+        .iter()
+        .zip(visited_right)
+        .filter(|(_, visited)| *visited);
+    left.chain(right).map(|(id, _)| *id).collect()
+}
+
+/// Dense representation of a bipartite graph where vertices of both parts are replaced
+/// with their positions in `left` and `right` vectors.
+struct Bipartite<I> {
+    left: Vec<I>,
+    right: Vec<I>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<I: Index> Bipartite<I> {
+    fn new<G: VertexProvider<I>>(graph: &G, left_side: impl IntoIterator<Item = I>) -> Self {
+        let vertices = graph.vertices();
+        let mut left_set = HashSet::new();
+        let left = left_side
+            .into_iter()
+            .filter(|id| vertices.contains(*id) && left_set.insert(*id))
+            .collect::<Vec<_>>();
+
+        let mut right = Vec::new();
+        let mut positions = HashMap::new();
+        let adjacency = left
+            .iter()
+            .map(|id| {
+                vertices
+                    .adjacent_to(*id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|neighbor| !left_set.contains(neighbor))
+                    .map(|neighbor| {
+                        *positions.entry(neighbor).or_insert_with(|| {
+                            right.push(neighbor);
+                            right.len() - 1
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            left,
+            right,
+            adjacency,
+        }
+    }
+
+    /// Run Hopcroft-Karp and return mates of the left and right vertices respectively.
+    fn maximum_matching(&self) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+        let mut pair_left = vec![None; self.left.len()];
+        let mut pair_right = vec![None; self.right.len()];
+        let mut distance = vec![UNREACHED; self.left.len()];
+
+        while let Some(length) = self.layer(&pair_left, &pair_right, &mut distance) {
+            let mut layers = Layers {
+                pair_left: &mut pair_left,
+                pair_right: &mut pair_right,
+                distance: &mut distance,
+                next: vec![0; self.left.len()],
+                length,
+            };
+            for u in 0..self.left.len() {
+                if layers.pair_left[u].is_none() {
+                    self.augment(u, &mut layers);
+                }
+            }
+        }
+        (pair_left, pair_right)
+    }
+
+    /// Build BFS layers of left vertices starting from the unmatched ones, stopping at the first
+    /// layer that reaches an unmatched right vertex. Returns the number of left layers of the
+    /// shortest augmenting paths, or `None` if there are no augmenting paths.
+    fn layer(
+        &self,
+        pair_left: &[Option<usize>],
+        pair_right: &[Option<usize>],
+        distance: &mut [usize],
+    ) -> Option<usize> {
+        let mut queue = VecDeque::new();
+        for (u, pair) in pair_left.iter().enumerate() {
+            distance[u] = match pair {
+                None => {
+                    queue.push_back(u);
+                    0
+                }
+                Some(_) => UNREACHED,
+            };
+        }
+
+        let mut length = UNREACHED;
+        while let Some(u) = queue.pop_front() {
+            // Vertices come in the order of layers, so the rest are beyond the shortest paths.
+            if distance[u] >= length {
+                break;
+            }
+            for &v in &self.adjacency[u] {
+                match pair_right[v] {
+                    None => length = distance[u] + 1,
+                    Some(w) if distance[w] == UNREACHED => {
+                        distance[w] = distance[u] + 1;
+                        queue.push_back(w);
+                    }
+                    Some(_) => (),
+                }
+            }
+        }
+        (length != UNREACHED).then_some(length)
+    }
+
+    /// Look for a shortest augmenting path from `root` along the BFS layers and flip it if found.
+    /// The search keeps its path on an explicit stack, so long paths do not overflow the call
+    /// stack, and resumes scanning each vertex from the first untried neighbor.
+    fn augment(&self, root: usize, layers: &mut Layers) -> bool {
+        // Left vertices of the path, and right vertices leading from each of them to the next.
+        let mut path = vec![root];
+        let mut steps = Vec::new();
+        while let Some(&u) = path.last() {
+            let v = match self.adjacency[u].get(layers.next[u]) {
+                Some(v) => *v,
+                None => {
+                    layers.distance[u] = UNREACHED;
+                    path.pop();
+                    steps.pop();
+                    continue;
+                }
+            };
+            layers.next[u] += 1;
+            match layers.pair_right[v] {
+                None if layers.distance[u] + 1 == layers.length => {
+                    steps.push(v);
+                    for (u, v) in path.into_iter().zip(steps) {
+                        layers.pair_left[u] = Some(v);
+                        layers.pair_right[v] = Some(u);
+                    }
+                    return true;
+                }
+                Some(w) if layers.distance[w] == layers.distance[u] + 1 => {
+                    path.push(w);
+                    steps.push(v);
+                }
+                _ => (),
+            }
+        }
+        false
+    }
+}
+
+/// State of a phase of Hopcroft-Karp: the current matching, BFS layers of left vertices, and
+/// positions of the next neighbor to try for each left vertex.
+struct Layers<'a> {
+    pair_left: &'a mut [Option<usize>],
+    pair_right: &'a mut [Option<usize>],
+    distance: &'a mut [usize],
+    next: Vec<usize>,
+    /// The number of left layers of the shortest augmenting paths.
+    length: usize,
+}
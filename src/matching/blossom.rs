@@ -0,0 +1,159 @@
+use super::Matching;
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+use std::collections::VecDeque;
+
+const NONE: usize = usize::MAX;
+
+/// Find the maximum-cardinality matching in a general undirected graph using Edmonds' blossom
+/// algorithm. Unlike [`hopcroft_karp`](super::hopcroft_karp), it does not require the graph to be
+/// bipartite: odd cycles (blossoms) are contracted while searching for augmenting paths.
+/// The algorithm runs in `O(V^3)` time.
+pub fn maximum_matching<I, G>(graph: &G) -> Matching<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let mut blossom = Blossom::new(dense.adjacency);
+    blossom.solve();
+
+    let mut matching = Matching::new();
+    for (v, mate) in blossom.mate.iter().enumerate() {
+        if *mate != NONE && v < *mate {
+            matching.insert(dense.ids[v], dense.ids[*mate]);
+        }
+    }
+    matching
+}
+
+/// State of the blossom algorithm over a dense representation of the graph.
+struct Blossom {
+    adjacency: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    parent: Vec<usize>,
+    base: Vec<usize>,
+    used: Vec<bool>,
+    in_blossom: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl Blossom {
+    fn new(adjacency: Vec<Vec<usize>>) -> Self {
+        let n = adjacency.len();
+        Self {
+            adjacency,
+            mate: vec![NONE; n],
+            parent: vec![NONE; n],
+            base: (0..n).collect(),
+            used: vec![false; n],
+            in_blossom: vec![false; n],
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn solve(&mut self) {
+        // A greedy initial matching considerably reduces the number of searches.
+        for v in 0..self.adjacency.len() {
+            if self.mate[v] != NONE {
+                continue;
+            }
+            let free = self.adjacency[v].iter().find(|to| self.mate[**to] == NONE);
+            if let Some(&to) = free {
+                self.mate[v] = to;
+                self.mate[to] = v;
+            }
+        }
+        for root in 0..self.adjacency.len() {
+            if self.mate[root] != NONE {
+                continue;
+            }
+            let mut v = self.find_path(root);
+            while v != NONE {
+                let parent = self.parent[v];
+                let next = self.mate[parent];
+                self.mate[v] = parent;
+                self.mate[parent] = v;
+                v = next;
+            }
+        }
+    }
+
+    /// Search for an augmenting path from the `root`, returning its free endpoint.
+    fn find_path(&mut self, root: usize) -> usize {
+        let n = self.adjacency.len();
+        self.used.iter_mut().for_each(|used| *used = false);
+        self.parent.iter_mut().for_each(|parent| *parent = NONE);
+        self.base
+            .iter_mut()
+            .enumerate()
+            .for_each(|(v, base)| *base = v);
+        self.queue.clear();
+
+        self.used[root] = true;
+        self.queue.push_back(root);
+        while let Some(v) = self.queue.pop_front() {
+            for i in 0..self.adjacency[v].len() {
+                let to = self.adjacency[v][i];
+                if self.base[v] == self.base[to] || self.mate[v] == to {
+                    continue;
+                }
+                if to == root || self.mate[to] != NONE && self.parent[self.mate[to]] != NONE {
+                    let current_base = self.lowest_common_ancestor(v, to);
+                    self.in_blossom.iter_mut().for_each(|flag| *flag = false);
+                    self.mark_path(v, current_base, to);
+                    self.mark_path(to, current_base, v);
+                    for u in 0..n {
+                        if self.in_blossom[self.base[u]] {
+                            self.base[u] = current_base;
+                            if !self.used[u] {
+                                self.used[u] = true;
+                                self.queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[to] == NONE {
+                    self.parent[to] = v;
+                    if self.mate[to] == NONE {
+                        return to;
+                    }
+                    let next = self.mate[to];
+                    self.used[next] = true;
+                    self.queue.push_back(next);
+                }
+            }
+        }
+        NONE
+    }
+
+    fn lowest_common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        let mut visited = vec![false; self.adjacency.len()];
+        loop {
+            a = self.base[a];
+            visited[a] = true;
+            if self.mate[a] == NONE {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+        loop {
+            b = self.base[b];
+            if visited[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    fn mark_path(&mut self, mut v: usize, base: usize, mut child: usize) {
+        while self.base[v] != base {
+            self.in_blossom[self.base[v]] = true;
+            self.in_blossom[self.base[self.mate[v]]] = true;
+            self.parent[v] = child;
+            child = self.mate[v];
+            v = self.parent[self.mate[v]];
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::Matching;
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::topology::Topology;
+
+/// Find a maximal matching with a single greedy pass over the vertices. The resulting matching
+/// cannot be extended by adding an edge, but it is not necessarily maximum: it is guaranteed
+/// to contain at least half as many edges as the maximum one. The algorithm runs in linear time
+/// and can serve as a fallback for graphs too large for exact algorithms.
+pub fn maximal_matching<I, G>(graph: &G) -> Matching<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let vertices = graph.vertices();
+    let mut matching = Matching::new();
+    for id in vertices.iter() {
+        if matching.is_matched(id) {
+            continue;
+        }
+        let mate = vertices
+            .adjacent_to(id)
+            .into_iter()
+            .flatten()
+            .find(|neighbor| *neighbor != id && !matching.is_matched(*neighbor));
+        if let Some(mate) = mate {
+            matching.insert(id, mate);
+        }
+    }
+    matching
+}
@@ -0,0 +1,126 @@
+/// The result of solving an assignment problem: pairs of assigned left and right items
+/// along with the total cost of the assignment.
+#[derive(Clone, Debug)]
+pub struct Assignment<I> {
+    /// Assigned pairs in `(left, right)` order.
+    pub pairs: Vec<(I, I)>,
+    /// Sum of costs of all assigned pairs.
+    pub cost: f64,
+}
+
+/// Solve the weighted assignment problem with the Hungarian algorithm, finding the minimum-cost
+/// matching between `left` and `right` items in `O(n^2 * m)` time. Every item of the smaller set
+/// is assigned to exactly one item of the larger set, so rectangular problems, like having more
+/// workers than jobs, leave some items of the larger set unassigned. To maximize the total weight
+/// instead, negate the costs.
+///
+/// # Panics
+///
+/// Panics if the `cost` of any pair is infinite or NaN.
+pub fn hungarian<I, F>(
+    left: impl IntoIterator<Item = I>,
+    right: impl IntoIterator<Item = I>,
+    cost: F,
+) -> Assignment<I>
+where
+    I: Copy,
+    F: Fn(I, I) -> f64,
+{
+    let left = left.into_iter().collect::<Vec<_>>();
+    let right = right.into_iter().collect::<Vec<_>>();
+    let transposed = left.len() > right.len();
+    let (rows, columns) = match transposed {
+        false => (&left, &right),
+        true => (&right, &left),
+    };
+    let costs = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| match transposed {
+                    false => cost(*row, *column),
+                    true => cost(*column, *row),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        costs.iter().flatten().all(|cost| cost.is_finite()),
+        "costs must be finite"
+    );
+
+    let mut pairs = Vec::with_capacity(rows.len());
+    let mut total = 0.0;
+    for (row, column) in solve(&costs) {
+        total += costs[row][column];
+        pairs.push(match transposed {
+            false => (rows[row], columns[column]),
+            true => (columns[column], rows[row]),
+        });
+    }
+    Assignment { pairs, cost: total }
+}
+
+/// Find the minimum-cost assignment of every row of the matrix to a distinct column. The matrix
+/// must have no more rows than columns. Returns assigned pairs in `(row, column)` order.
+fn solve(costs: &[Vec<f64>]) -> Vec<(usize, usize)> {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, Vec::len);
+
+    // Potentials and matching use 1-based indices, with the 0th column serving as a sentinel
+    // that holds the row currently being inserted.
+    let mut u = vec![0.0; rows + 1];
+    let mut v = vec![0.0; columns + 1];
+    let mut matched = vec![0; columns + 1];
+    let mut way = vec![0; columns + 1];
+
+    for row in 1..=rows {
+        matched[0] = row;
+        let mut current = 0;
+        let mut min_slack = vec![f64::INFINITY; columns + 1];
+        let mut used = vec![false; columns + 1];
+        loop {
+            used[current] = true;
+            let i = matched[current];
+            let mut delta = f64::INFINITY;
+            let mut next = 0;
+            for j in 1..=columns {
+                if used[j] {
+                    continue;
+                }
+                let slack = costs[i - 1][j - 1] - u[i] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = current;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    next = j;
+                }
+            }
+            for j in 0..=columns {
+                if used[j] {
+                    u[matched[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            current = next;
+            if matched[current] == 0 {
+                break;
+            }
+        }
+        while current != 0 {
+            let previous = way[current];
+            matched[current] = matched[previous];
+            current = previous;
+        }
+    }
+
+    (1..=columns)
+        .filter(|j| matched[*j] != 0)
+        .map(|j| (matched[j] - 1, j - 1))
+        .collect()
+}
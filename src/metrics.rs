@@ -0,0 +1,7 @@
+//! This module is a home for graph metrics that describe the structure of a graph with numbers,
+//! like distance-based characteristics, the number of triangles or the clustering coefficient.
+
+mod distance;
+pub mod triangles;
+
+pub use distance::*;
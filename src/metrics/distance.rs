@@ -0,0 +1,193 @@
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::{Dense, ShortestPaths};
+
+use std::collections::{HashMap, VecDeque};
+
+/// Compute the eccentricity of each vertex, i.e. the largest distance from it to any other vertex.
+/// Edge lengths are given by the optional `weight` function, which must be non-negative, and are
+/// all equal to one without it. Distances follow the vertex topology adjacency, so in directed
+/// graphs they are measured along outgoing edges. If some vertex cannot be reached from another,
+/// the eccentricity of the latter is infinite.
+pub fn eccentricity<I, G, W>(graph: &G, weight: Option<W>) -> HashMap<I, f64>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    let dense = Dense::new(&graph.vertices());
+    let weights = weight.map(|weight| dense.weights(weight));
+    let eccentricities = eccentricities(&dense, weights.as_deref());
+    dense.ids.iter().copied().zip(eccentricities).collect()
+}
+
+/// Compute the diameter of a graph, i.e. the largest eccentricity of its vertices. The diameter
+/// of a disconnected graph is infinite, and the diameter of an empty graph is zero. See
+/// [`eccentricity`] for the meaning of `weight`.
+pub fn diameter<I, G, W>(graph: &G, weight: Option<W>) -> f64
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    Extremes::of(graph, weight).diameter
+}
+
+/// Compute the radius of a graph, i.e. the smallest eccentricity of its vertices. The radius
+/// of an empty graph is zero. See [`eccentricity`] for the meaning of `weight`.
+pub fn radius<I, G, W>(graph: &G, weight: Option<W>) -> f64
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    Extremes::of(graph, weight).radius
+}
+
+/// Find the center of a graph, i.e. all vertices whose eccentricity equals the radius.
+/// See [`eccentricity`] for the meaning of `weight`.
+pub fn center<I, G, W>(graph: &G, weight: Option<W>) -> Vec<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    Extremes::of(graph, weight).center
+}
+
+/// Find the periphery of a graph, i.e. all vertices whose eccentricity equals the diameter.
+/// See [`eccentricity`] for the meaning of `weight`.
+pub fn periphery<I, G, W>(graph: &G, weight: Option<W>) -> Vec<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+    W: Fn(I, I) -> f64,
+{
+    Extremes::of(graph, weight).periphery
+}
+
+/// Estimate the diameter of a large graph with a double sweep: a BFS from an arbitrary vertex
+/// finds the farthest vertex, and the eccentricity of the latter is returned. The estimate never
+/// exceeds the diameter of the connected component of the starting vertex, is exact for trees,
+/// and is usually exact or close for real-world graphs. It only takes two BFS runs, so every edge
+/// has a unit length. The estimate is a float to be comparable with [`diameter`].
+pub fn approximate_diameter<I, G>(graph: &G) -> f64
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    if dense.len() == 0 {
+        return 0.0;
+    }
+    let (farthest, _) = sweep(&dense, 0);
+    let (_, distance) = sweep(&dense, farthest);
+    distance as f64
+}
+
+/// Compute the girth of an undirected graph, i.e. the length of its shortest cycle. Returns
+/// `None` for acyclic graphs. The algorithm runs a BFS from every vertex, taking `O(V * E)` time.
+pub fn girth<I, G>(graph: &G) -> Option<usize>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let n = dense.len();
+    let mut girth = None;
+    let mut distance = vec![usize::MAX; n];
+    let mut parent = vec![usize::MAX; n];
+    for root in 0..n {
+        distance.iter_mut().for_each(|d| *d = usize::MAX);
+        distance[root] = 0;
+        parent[root] = usize::MAX;
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            // Cycles found further from the root cannot be shorter than the current one.
+            if girth.is_some_and(|girth| 2 * distance[v] + 1 >= girth) {
+                break;
+            }
+            for &u in &dense.adjacency[v] {
+                if distance[u] == usize::MAX {
+                    distance[u] = distance[v] + 1;
+                    parent[u] = v;
+                    queue.push_back(u);
+                } else if parent[v] != u {
+                    let length = distance[u] + distance[v] + 1;
+                    girth = Some(girth.map_or(length, |girth: usize| girth.min(length)));
+                }
+            }
+        }
+    }
+    girth
+}
+
+/// Diameter, radius, center and periphery, computed together from eccentricities.
+struct Extremes<I> {
+    diameter: f64,
+    radius: f64,
+    center: Vec<I>,
+    periphery: Vec<I>,
+}
+
+impl<I: Index> Extremes<I> {
+    fn of<G, W>(graph: &G, weight: Option<W>) -> Self
+    where
+        G: VertexProvider<I>,
+        W: Fn(I, I) -> f64,
+    {
+        let dense = Dense::new(&graph.vertices());
+        let weights = weight.map(|weight| dense.weights(weight));
+        let eccentricities = eccentricities(&dense, weights.as_deref());
+        let diameter = eccentricities.iter().copied().fold(0.0, f64::max);
+        let radius = match eccentricities.is_empty() {
+            true => 0.0,
+            false => eccentricities.iter().copied().fold(f64::INFINITY, f64::min),
+        };
+        let matching = |value: f64| {
+            dense
+                .ids
+                .iter()
+                .zip(&eccentricities)
+                .filter(|(_, eccentricity)| **eccentricity == value)
+                .map(|(id, _)| *id)
+                .collect()
+        };
+        Self {
+            diameter,
+            radius,
+            center: matching(radius),
+            periphery: matching(diameter),
+        }
+    }
+}
+
+fn eccentricities<I>(dense: &Dense<I>, weights: Option<&[Vec<f64>]>) -> Vec<f64> {
+    (0..dense.len())
+        .map(|source| {
+            let paths = ShortestPaths::new(dense, weights, source);
+            match paths.order.len() == dense.len() {
+                true => paths.distance.iter().copied().fold(0.0, f64::max),
+                false => f64::INFINITY,
+            }
+        })
+        .collect()
+}
+
+/// Run a BFS from the `source` and return the last discovered vertex with its distance.
+fn sweep<I>(dense: &Dense<I>, source: usize) -> (usize, usize) {
+    let mut distance = vec![usize::MAX; dense.len()];
+    let mut queue = VecDeque::from([source]);
+    let mut last = source;
+    distance[source] = 0;
+    while let Some(v) = queue.pop_front() {
+        last = v;
+        for &u in &dense.adjacency[v] {
+            if distance[u] == usize::MAX {
+                distance[u] = distance[v] + 1;
+                queue.push_back(u);
+            }
+        }
+    }
+    (last, distance[last])
+}
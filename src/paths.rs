@@ -0,0 +1,9 @@
+//! This module is a home for algorithms that look for walks with special properties, such as
+//! trails that visit every edge or every vertex of a graph exactly once.
+
+mod eulerian;
+mod hamiltonian;
+pub mod tsp;
+
+pub use eulerian::*;
+pub use hamiltonian::*;
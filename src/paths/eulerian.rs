@@ -0,0 +1,228 @@
+use crate::index::Index;
+use crate::providers::{EdgeProvider, VertexProvider};
+use crate::topology::Topology;
+
+use std::collections::HashMap;
+
+/// Reason why a graph has no Eulerian trail of the requested kind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EulerianError<I> {
+    /// Vertices of an undirected graph with an odd degree. A circuit allows none of them, and a
+    /// path allows two, which become its ends.
+    OddDegree(Vec<I>),
+    /// Vertices of a directed graph whose out-degree differs from the in-degree, together with
+    /// the difference. A circuit allows none of them, and a path allows one vertex with `1` and
+    /// one with `-1`, which become its start and end.
+    Imbalance(Vec<(I, isize)>),
+    /// Edges form several connected components. Holds a vertex from each of them.
+    Disconnected(Vec<I>),
+}
+
+/// Find an Eulerian circuit, a closed trail that traverses every edge exactly once, using
+/// Hierholzer's algorithm. Edges are returned in the order of traversal, each constructed from
+/// the pair of its ends oriented along the trail, so the circuit starts at the first element of
+/// the first edge. A graph without edges has an empty circuit.
+///
+/// Edges are treated as directed if the edge type tells apart both orientations of a pair of
+/// vertices, like `(I, I)` of directed adjacency lists, and as undirected otherwise, like
+/// [`UnorderedPair`](crate::utils::UnorderedPair).
+pub fn eulerian_circuit<I, G>(graph: &G) -> Result<Vec<G::Edge>, EulerianError<I>>
+where
+    I: Index,
+    G: VertexProvider<I> + EdgeProvider<I>,
+    G::Edge: From<(I, I)> + PartialEq,
+{
+    let directed = is_directed::<I, G::Edge>(graph.vertices().iter());
+    let multigraph = Multigraph::new(graph.vertices(), directed);
+    multigraph.check_degrees(false)?;
+    multigraph.check_connectivity()?;
+    Ok(multigraph.trail())
+}
+
+/// Find an Eulerian path, a trail that traverses every edge exactly once, using Hierholzer's
+/// algorithm. If the graph has a circuit, it is returned as the path. Edges are returned in the
+/// same form as in [`eulerian_circuit`].
+pub fn eulerian_path<I, G>(graph: &G) -> Result<Vec<G::Edge>, EulerianError<I>>
+where
+    I: Index,
+    G: VertexProvider<I> + EdgeProvider<I>,
+    G::Edge: From<(I, I)> + PartialEq,
+{
+    let directed = is_directed::<I, G::Edge>(graph.vertices().iter());
+    let multigraph = Multigraph::new(graph.vertices(), directed);
+    multigraph.check_degrees(true)?;
+    multigraph.check_connectivity()?;
+    Ok(multigraph.trail())
+}
+
+/// Check whether the edge type distinguishes the orientations of a pair of vertices, using the
+/// first two of the given vertices. Graphs with less than two vertices can only have loops, so
+/// they are treated as undirected.
+fn is_directed<I, E>(mut ids: impl Iterator<Item = I>) -> bool
+where
+    I: Copy,
+    E: From<(I, I)> + PartialEq,
+{
+    match (ids.next(), ids.next()) {
+        (Some(a), Some(b)) => E::from((a, b)) != E::from((b, a)),
+        _ => false,
+    }
+}
+
+/// Edge list of a graph with dense vertex indices. Edges of undirected graphs are stored once.
+struct Multigraph<I> {
+    ids: Vec<I>,
+    directed: bool,
+    edges: Vec<(usize, usize)>,
+    /// Indices of edges that can be traversed from each vertex.
+    incidence: Vec<Vec<usize>>,
+}
+
+impl<I: Index> Multigraph<I> {
+    fn new<T: Topology<Item = I>>(topology: T, directed: bool) -> Self {
+        let ids = topology.iter().collect::<Vec<_>>();
+        let positions = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect::<HashMap<_, _>>();
+        let mut edges = Vec::new();
+        let mut incidence = vec![Vec::new(); ids.len()];
+        for (a, id) in ids.iter().enumerate() {
+            let adjacent = topology.adjacent_to(*id).into_iter().flatten();
+            for b in adjacent.filter_map(|id| positions.get(&id).copied()) {
+                if !directed && b < a {
+                    continue;
+                }
+                incidence[a].push(edges.len());
+                if !directed && a != b {
+                    incidence[b].push(edges.len());
+                }
+                edges.push((a, b));
+            }
+        }
+        Self {
+            ids,
+            directed,
+            edges,
+            incidence,
+        }
+    }
+
+    /// Differences between outbound and inbound edges of each vertex in directed graphs, or
+    /// degrees in undirected ones. A self-loop adds two to the degree.
+    fn balances(&self) -> Vec<isize> {
+        let mut balances = vec![0; self.ids.len()];
+        for &(a, b) in &self.edges {
+            balances[a] += 1;
+            balances[b] += if self.directed { -1 } else { 1 };
+        }
+        balances
+    }
+
+    /// Make sure that the degrees allow a circuit, or a path if `path` is set.
+    fn check_degrees(&self, path: bool) -> Result<(), EulerianError<I>> {
+        let balances = self.balances();
+        if self.directed {
+            let imbalance = balances
+                .iter()
+                .enumerate()
+                .filter(|(_, balance)| **balance != 0)
+                .map(|(v, balance)| (self.ids[v], *balance))
+                .collect::<Vec<_>>();
+            let ends = imbalance.len() == 2 && imbalance.iter().all(|(_, b)| b.abs() == 1);
+            match imbalance.is_empty() || (path && ends) {
+                true => Ok(()),
+                false => Err(EulerianError::Imbalance(imbalance)),
+            }
+        } else {
+            let odd = balances
+                .iter()
+                .enumerate()
+                .filter(|(_, degree)| **degree % 2 == 1)
+                .map(|(v, _)| self.ids[v])
+                .collect::<Vec<_>>();
+            match odd.is_empty() || (path && odd.len() == 2) {
+                true => Ok(()),
+                false => Err(EulerianError::OddDegree(odd)),
+            }
+        }
+    }
+
+    /// Make sure that all edges belong to the same weakly connected component.
+    fn check_connectivity(&self) -> Result<(), EulerianError<I>> {
+        let n = self.ids.len();
+        let mut neighbors = vec![Vec::new(); n];
+        for &(a, b) in &self.edges {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+        let mut visited = vec![false; n];
+        let mut representatives = Vec::new();
+        for root in (0..n).filter(|v| !neighbors[*v].is_empty()) {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                for &u in &neighbors[v] {
+                    if !visited[u] {
+                        visited[u] = true;
+                        stack.push(u);
+                    }
+                }
+            }
+            representatives.push(self.ids[root]);
+        }
+        match representatives.len() <= 1 {
+            true => Ok(()),
+            false => Err(EulerianError::Disconnected(representatives)),
+        }
+    }
+
+    /// Vertex to start the trail from: the one with a surplus of outbound edges or an odd degree
+    /// if there is one, or any vertex with edges otherwise.
+    fn start(&self) -> Option<usize> {
+        let balances = self.balances();
+        let unbalanced = (0..self.ids.len()).find(|v| match self.directed {
+            true => balances[*v] > 0,
+            false => balances[*v] % 2 == 1,
+        });
+        unbalanced.or_else(|| (0..self.ids.len()).find(|v| !self.incidence[*v].is_empty()))
+    }
+
+    /// Hierholzer's algorithm with an explicit stack. The degree and connectivity conditions
+    /// must be checked beforehand.
+    fn trail<E: From<(I, I)>>(&self) -> Vec<E> {
+        let start = match self.start() {
+            Some(start) => start,
+            None => return Vec::new(),
+        };
+        let mut used = vec![false; self.edges.len()];
+        let mut next = vec![0; self.ids.len()];
+        let mut stack = vec![start];
+        let mut trail = Vec::with_capacity(self.edges.len());
+        while let Some(&v) = stack.last() {
+            let incidence = &self.incidence[v];
+            while next[v] < incidence.len() && used[incidence[next[v]]] {
+                next[v] += 1;
+            }
+            match incidence.get(next[v]) {
+                Some(&edge) => {
+                    used[edge] = true;
+                    let (a, b) = self.edges[edge];
+                    stack.push(if a == v { b } else { a });
+                }
+                None => {
+                    stack.pop();
+                    if let Some(&from) = stack.last() {
+                        trail.push((self.ids[from], self.ids[v]).into());
+                    }
+                }
+            }
+        }
+        trail.reverse();
+        trail
+    }
+}
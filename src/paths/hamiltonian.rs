@@ -0,0 +1,88 @@
+use crate::index::Index;
+use crate::providers::VertexProvider;
+use crate::utils::Dense;
+
+/// Outcome of a Hamiltonian path search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HamiltonianSearch<I> {
+    /// Vertices of the found path in the order of traversal.
+    Found(Vec<I>),
+    /// The graph has no Hamiltonian path.
+    Absent,
+    /// The budget ran out before the search was complete.
+    OutOfBudget,
+}
+
+/// Find a Hamiltonian path, a path that visits every vertex exactly once, using backtracking
+/// search. Edge directions are respected. The search extends the path with the neighbor that has
+/// the fewest unvisited neighbors first (Warnsdorff's rule), which finds paths in grids and other
+/// sparse graphs quickly. The problem is NP-complete, so the search is limited by the `budget` of
+/// path extensions. A graph without vertices has an empty path.
+pub fn hamiltonian_path<I, G>(graph: &G, budget: usize) -> HamiltonianSearch<I>
+where
+    I: Index,
+    G: VertexProvider<I>,
+{
+    let dense = Dense::new(&graph.vertices());
+    let n = dense.len();
+    if n == 0 {
+        return HamiltonianSearch::Found(Vec::new());
+    }
+
+    // A path in an undirected graph has at most two vertices of degree one and none of degree
+    // zero, which allows rejecting some graphs upfront.
+    let mut sorted = dense.adjacency.clone();
+    sorted
+        .iter_mut()
+        .for_each(|adjacent| adjacent.sort_unstable());
+    let symmetric = (0..n).all(|v| {
+        sorted[v]
+            .iter()
+            .all(|u| sorted[*u].binary_search(&v).is_ok())
+    });
+    let leaves = sorted.iter().filter(|adjacent| adjacent.len() == 1).count();
+    let isolated = sorted.iter().any(Vec::is_empty);
+    if n > 1 && symmetric && (isolated || leaves > 2) {
+        return HamiltonianSearch::Absent;
+    }
+
+    // Vertices with fewer connections are better starts, as they have to be path ends anyway.
+    let mut starts = (0..n).collect::<Vec<_>>();
+    starts.sort_by_key(|v| dense.adjacency[*v].len());
+
+    let mut visited = vec![false; n];
+    let mut path = Vec::with_capacity(n);
+    let mut stack = vec![(starts, 0)];
+    let mut steps = 0;
+    while let Some(depth) = stack.len().checked_sub(1) {
+        // Undo the choice previously made at this depth.
+        if path.len() > depth {
+            let v = path.pop().unwrap();
+            visited[v] = false;
+        }
+        let (candidates, next) = &mut stack[depth];
+        let v = match candidates.get(*next) {
+            Some(v) => *v,
+            None => {
+                stack.pop();
+                continue;
+            }
+        };
+        *next += 1;
+        if steps == budget {
+            return HamiltonianSearch::OutOfBudget;
+        }
+        steps += 1;
+
+        visited[v] = true;
+        path.push(v);
+        if path.len() == n {
+            return HamiltonianSearch::Found(path.into_iter().map(|v| dense.ids[v]).collect());
+        }
+        let unvisited = |v: usize| dense.adjacency[v].iter().filter(|u| !visited[**u]);
+        let mut candidates = unvisited(v).copied().collect::<Vec<_>>();
+        candidates.sort_by_key(|u| unvisited(*u).count());
+        stack.push((candidates, 0));
+    }
+    HamiltonianSearch::Absent
+}
@@ -0,0 +1,204 @@
+//! Solvers of the travelling salesman problem: find the cheapest closed tour that visits every
+//! item exactly once. Solvers take the items and a cost closure, so the items do not have to be
+//! connected directly in any graph. For example, the cost can be a shortest path distance.
+
+/// A closed tour through items along with its total cost.
+#[derive(Clone, Debug)]
+pub struct Tour<I> {
+    /// Items in the order of visiting. The tour returns from the last item to the first one.
+    pub items: Vec<I>,
+    /// Sum of costs of all steps of the tour, including the return to the first item.
+    pub cost: f64,
+}
+
+impl<I: Copy> Tour<I> {
+    /// Create a tour visiting `items` in the given order and compute its cost.
+    pub fn new<F>(items: Vec<I>, cost: F) -> Self
+    where
+        F: Fn(I, I) -> f64,
+    {
+        let total = match items.len() {
+            0 | 1 => 0.0,
+            n => (0..n).map(|k| cost(items[k], items[(k + 1) % n])).sum(),
+        };
+        Tour { items, cost: total }
+    }
+}
+
+/// Maximum number of items accepted by [`held_karp`].
+pub const HELD_KARP_LIMIT: usize = 20;
+
+/// Build a tour by starting from the first item and repeatedly moving to the cheapest
+/// unvisited one. Runs in `O(n^2)` time.
+pub fn nearest_neighbor<I, F>(items: impl IntoIterator<Item = I>, cost: F) -> Tour<I>
+where
+    I: Copy,
+    F: Fn(I, I) -> f64,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    let mut visited = vec![false; items.len()];
+    let mut order = Vec::with_capacity(items.len());
+    let mut current = match items.is_empty() {
+        true => return Tour::new(items, cost),
+        false => 0,
+    };
+    visited[0] = true;
+    order.push(items[0]);
+    while order.len() < items.len() {
+        let next = (0..items.len())
+            .filter(|k| !visited[*k])
+            .min_by(|a, b| {
+                let a = cost(items[current], items[*a]);
+                a.total_cmp(&cost(items[current], items[*b]))
+            })
+            .unwrap();
+        visited[next] = true;
+        order.push(items[next]);
+        current = next;
+    }
+    Tour::new(order, cost)
+}
+
+/// Improve a `tour` with 2-opt moves: while replacing a pair of steps with the pair connecting
+/// their ends crosswise makes the tour cheaper, reverse the part of the tour between them. The
+/// cost is assumed to be symmetric, as reversed parts are traversed in the opposite direction.
+pub fn two_opt<I, F>(tour: Tour<I>, cost: F) -> Tour<I>
+where
+    I: Copy,
+    F: Fn(I, I) -> f64,
+{
+    const EPSILON: f64 = 1e-12;
+    let mut items = tour.items;
+    let n = items.len();
+    let mut improved = n >= 4;
+    while improved {
+        improved = false;
+        for i in 0..n - 2 {
+            // Steps sharing an item cannot be exchanged.
+            let last = if i == 0 { n - 1 } else { n };
+            for j in i + 2..last {
+                let (a, b) = (items[i], items[i + 1]);
+                let (c, d) = (items[j], items[(j + 1) % n]);
+                let delta = cost(a, c) + cost(b, d) - cost(a, b) - cost(c, d);
+                if delta < -EPSILON {
+                    items[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    Tour::new(items, cost)
+}
+
+/// Build a tour from a minimum spanning tree: the tree is walked around, which traverses every
+/// tree edge twice, and repeated items are skipped. For symmetric costs that satisfy the triangle
+/// inequality, the tour costs at most twice as much as the optimal one. Runs in `O(n^2)` time.
+pub fn mst_doubling<I, F>(items: impl IntoIterator<Item = I>, cost: F) -> Tour<I>
+where
+    I: Copy,
+    F: Fn(I, I) -> f64,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    let n = items.len();
+    if n == 0 {
+        return Tour::new(items, cost);
+    }
+
+    // Prim's algorithm on the complete graph.
+    let mut in_tree = vec![false; n];
+    let mut distance = vec![f64::INFINITY; n];
+    let mut parent = vec![0; n];
+    let mut children = vec![Vec::new(); n];
+    distance[0] = 0.0;
+    for _ in 0..n {
+        let v = (0..n)
+            .filter(|v| !in_tree[*v])
+            .min_by(|a, b| distance[*a].total_cmp(&distance[*b]))
+            .unwrap();
+        in_tree[v] = true;
+        if v != 0 {
+            children[parent[v]].push(v);
+        }
+        for u in (0..n).filter(|u| !in_tree[*u]) {
+            let weight = cost(items[v], items[u]);
+            if weight < distance[u] {
+                distance[u] = weight;
+                parent[u] = v;
+            }
+        }
+    }
+
+    // Preorder walk of the tree is the walk around it with repeated items skipped.
+    let mut order = Vec::with_capacity(n);
+    let mut stack = vec![0];
+    while let Some(v) = stack.pop() {
+        order.push(items[v]);
+        stack.extend(children[v].iter().rev());
+    }
+    Tour::new(order, cost)
+}
+
+/// Find an optimal tour with the Held-Karp dynamic programming algorithm in `O(2^n * n^2)` time
+/// and `O(2^n * n)` memory. The cost does not have to be symmetric. Returns `None` if there are
+/// more than [`HELD_KARP_LIMIT`] items.
+pub fn held_karp<I, F>(items: impl IntoIterator<Item = I>, cost: F) -> Option<Tour<I>>
+where
+    I: Copy,
+    F: Fn(I, I) -> f64,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    let n = items.len();
+    if n > HELD_KARP_LIMIT {
+        return None;
+    }
+    if n <= 2 {
+        return Some(Tour::new(items, cost));
+    }
+
+    // The tour starts at the first item. Subsets consist of the remaining m items, and
+    // best[subset * m + last] is the cost of the cheapest path from the first item through the
+    // subset that ends at its member `last`.
+    let m = n - 1;
+    let costs = (0..n)
+        .map(|a| (0..n).map(|b| cost(items[a], items[b])).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut best = vec![f64::INFINITY; (1 << m) * m];
+    let mut previous = vec![0u8; (1 << m) * m];
+    for last in 0..m {
+        best[(1 << last) * m + last] = costs[0][last + 1];
+    }
+    for subset in 1usize..1 << m {
+        for last in (0..m).filter(|last| subset & (1 << last) != 0) {
+            let current = best[subset * m + last];
+            for next in (0..m).filter(|next| subset & (1 << next) == 0) {
+                let extended = subset | (1 << next);
+                let candidate = current + costs[last + 1][next + 1];
+                // Unreachable paths still get a predecessor, so any tour can be restored.
+                let unset = best[extended * m + next] == f64::INFINITY;
+                if candidate < best[extended * m + next] || unset {
+                    best[extended * m + next] = candidate;
+                    previous[extended * m + next] = last as u8;
+                }
+            }
+        }
+    }
+
+    let full = (1 << m) - 1;
+    let mut last = (0..m)
+        .min_by(|a, b| {
+            let a = best[full * m + a] + costs[a + 1][0];
+            a.total_cmp(&(best[full * m + b] + costs[b + 1][0]))
+        })
+        .unwrap();
+    let mut subset = full;
+    let mut order = Vec::with_capacity(n);
+    while subset != 0 {
+        order.push(items[last + 1]);
+        let before = previous[subset * m + last] as usize;
+        subset &= !(1 << last);
+        last = before;
+    }
+    order.push(items[0]);
+    order.reverse();
+    Some(Tour::new(order, cost))
+}
@@ -1,7 +1,14 @@
 //! This modules defines tratis the provide topologies for a graph. In general, a graph
 //! implementation provides two topologie, one for edges and one for vertices. They are
 //! typically represented by separate structs implementing the [`Topology`] trait.
+//!
+//! Besides topologies, providers expose basic statistics of a graph like its order, size and
+//! vertex degrees. They come with default implementations derived from the topologies, which
+//! graphs can override when they know the answer directly. Aggregates like the degree histogram
+//! query every vertex, so their cost is the order of the graph times the cost of the per-vertex
+//! method they rely on.
 
+use crate::index::Index;
 use crate::topology::Topology;
 
 /// This trait defines methods to access overall number of vertices (graph order) and
@@ -17,6 +24,101 @@ pub trait VertexProvider<I> {
 
     /// `vertices` returns the instance of vertex topology.
     fn vertices(&self) -> Self::Vertices<'_>;
+
+    /// `degree` returns the number of edges incident to the vertex, or `None` if there is no
+    /// such vertex. By default, it counts the vertices adjacent to `item`, which is correct for
+    /// undirected graphs. Directed graphs should override it to count both incoming and
+    /// outgoing edges.
+    fn degree(&self, item: I) -> Option<usize> {
+        self.vertices().adjacent_to(item).map(Iterator::count)
+    }
+
+    /// `out_degree` returns the number of edges leaving the vertex, or `None` if there is no
+    /// such vertex. For undirected graphs, it is the same as the degree.
+    fn out_degree(&self, item: I) -> Option<usize> {
+        self.vertices().adjacent_to(item).map(Iterator::count)
+    }
+
+    /// `in_degree` returns the number of edges entering the vertex, or `None` if there is no
+    /// such vertex. For undirected graphs, it is the same as the degree. The default
+    /// implementation scans adjacency of all vertices, so it takes `O(V + E)` time.
+    fn in_degree(&self, item: I) -> Option<usize>
+    where
+        I: Index,
+    {
+        let vertices = self.vertices();
+        if !vertices.contains(item) {
+            return None;
+        }
+        let count = vertices
+            .iter()
+            .filter_map(|id| vertices.adjacent_to(id))
+            .flatten()
+            .filter(|id| *id == item)
+            .count();
+        Some(count)
+    }
+
+    /// `degree_histogram` returns the number of vertices for each degree, so that the value at
+    /// index `d` is the number of vertices with degree `d`. It calls `degree` for every vertex.
+    fn degree_histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        for degree in self.vertices().iter().filter_map(|id| self.degree(id)) {
+            if histogram.len() <= degree {
+                histogram.resize(degree + 1, 0);
+            }
+            histogram[degree] += 1;
+        }
+        histogram
+    }
+
+    /// `average_degree` returns the mean degree of vertices, or zero for an empty graph. It calls
+    /// `degree` for every vertex.
+    fn average_degree(&self) -> f64 {
+        let total = self
+            .vertices()
+            .iter()
+            .filter_map(|id| self.degree(id))
+            .sum::<usize>();
+        match self.order() {
+            0 => 0.0,
+            order => total as f64 / order as f64,
+        }
+    }
+
+    /// `max_degree` returns the largest degree of vertices, or `None` for an empty graph. Like
+    /// the other aggregates, it calls `degree` for every vertex.
+    fn max_degree(&self) -> Option<usize> {
+        self.vertices()
+            .iter()
+            .filter_map(|id| self.degree(id))
+            .max()
+    }
+
+    /// `min_degree` returns the smallest degree of vertices, or `None` for an empty graph. Like
+    /// the other aggregates, it calls `degree` for every vertex.
+    fn min_degree(&self) -> Option<usize> {
+        self.vertices()
+            .iter()
+            .filter_map(|id| self.degree(id))
+            .min()
+    }
+
+    /// `density` returns the ratio of the number of edges to the largest possible number of edges
+    /// in a simple graph of the same order, or zero for graphs with less than two vertices. It
+    /// sums `out_degree` over all vertices, which counts every edge of an undirected graph from
+    /// both ends, so the same ratio holds for directed and undirected graphs.
+    fn density(&self) -> f64 {
+        let total = self
+            .vertices()
+            .iter()
+            .filter_map(|id| self.out_degree(id))
+            .sum::<usize>();
+        match self.order() {
+            0 | 1 => 0.0,
+            order => total as f64 / (order * (order - 1)) as f64,
+        }
+    }
 }
 
 /// This trait defines methods to access overall number of edges (graph size) and
@@ -1,7 +1,11 @@
 //! This module is home of various usefuls utils for graph manipulation.
 
+mod dense;
+mod shortest_paths;
 mod unordered_pair;
 
+pub(crate) use dense::Dense;
+pub(crate) use shortest_paths::ShortestPaths;
 pub use unordered_pair::*;
 
 /// Reverse trait provides a way to reverse some bidirectioanl objects such as graph edges.
@@ -66,3 +70,9 @@ impl<A, B, C> Collapse<A, B, C> for (Option<A>, Option<B>) {
         Some(f(a, b))
     }
 }
+
+/// Return no weight function for algorithms taking an optional `weight`, so every link has a unit
+/// weight. This is a shorthand for `None::<fn(I, I) -> f64>`, which otherwise needs a type.
+pub fn unweighted<I>() -> Option<fn(I, I) -> f64> {
+    None
+}
@@ -0,0 +1,66 @@
+use crate::index::Index;
+use crate::topology::Topology;
+
+use std::collections::HashMap;
+
+/// A compact snapshot of a topology where items are replaced with their positions in a vector.
+/// Many algorithms are much simpler and faster with dense integer indices, so they build this
+/// representation once and translate the result back to the graph's indices at the end.
+/// Self-loops are dropped.
+pub(crate) struct Dense<I> {
+    pub ids: Vec<I>,
+    pub positions: HashMap<I, usize>,
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl<I: Index> Dense<I> {
+    pub fn new<T: Topology<Item = I>>(topology: &T) -> Self {
+        let ids = topology.iter().collect::<Vec<_>>();
+        let positions = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect::<HashMap<_, _>>();
+        let adjacency = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| {
+                topology
+                    .adjacent_to(*id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|neighbor| positions.get(&neighbor).copied())
+                    .filter(|neighbor| *neighbor != position)
+                    .collect()
+            })
+            .collect();
+        Self {
+            ids,
+            positions,
+            adjacency,
+        }
+    }
+}
+
+impl<I: Copy> Dense<I> {
+    /// Evaluate the `weight` of every link, returning weights parallel to the adjacency lists.
+    pub fn weights(&self, weight: impl Fn(I, I) -> f64) -> Vec<Vec<f64>> {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .map(|(v, adjacent)| {
+                adjacent
+                    .iter()
+                    .map(|u| weight(self.ids[v], self.ids[*u]))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<I> Dense<I> {
+    /// Return the number of items.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+}
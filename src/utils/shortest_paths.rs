@@ -0,0 +1,107 @@
+use super::Dense;
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Shortest paths from a single source over a [`Dense`] topology, as required by Brandes-style
+/// algorithms. Unreachable items have infinite distance and no predecessors.
+pub(crate) struct ShortestPaths {
+    /// Reached items in the order of non-decreasing distance from the source.
+    pub order: Vec<usize>,
+    /// Distances from the source.
+    pub distance: Vec<f64>,
+    /// Number of different shortest paths from the source.
+    pub paths: Vec<f64>,
+    /// Predecessors of each item on the shortest paths.
+    pub predecessors: Vec<Vec<usize>>,
+}
+
+impl ShortestPaths {
+    /// Find shortest paths from the `source`. The `weights` are parallel to the adjacency lists
+    /// of the topology and must be non-negative; without them every link has a unit weight and
+    /// a BFS is used.
+    pub fn new<I>(dense: &Dense<I>, weights: Option<&[Vec<f64>]>, source: usize) -> Self {
+        let n = dense.len();
+        let mut paths = Self {
+            order: Vec::with_capacity(n),
+            distance: vec![f64::INFINITY; n],
+            paths: vec![0.0; n],
+            predecessors: vec![Vec::new(); n],
+        };
+        paths.distance[source] = 0.0;
+        paths.paths[source] = 1.0;
+        match weights {
+            None => paths.bfs(dense, source),
+            Some(weights) => paths.dijkstra(dense, weights, source),
+        }
+        paths
+    }
+
+    fn bfs<I>(&mut self, dense: &Dense<I>, source: usize) {
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            self.order.push(v);
+            for &u in &dense.adjacency[v] {
+                if self.distance[u].is_infinite() {
+                    self.distance[u] = self.distance[v] + 1.0;
+                    queue.push_back(u);
+                }
+                if self.distance[u] == self.distance[v] + 1.0 {
+                    self.paths[u] += self.paths[v];
+                    self.predecessors[u].push(v);
+                }
+            }
+        }
+    }
+
+    fn dijkstra<I>(&mut self, dense: &Dense<I>, weights: &[Vec<f64>], source: usize) {
+        let mut settled = vec![false; dense.len()];
+        let mut queue = BinaryHeap::from([Candidate(0.0, source)]);
+        while let Some(Candidate(distance, v)) = queue.pop() {
+            if settled[v] || distance > self.distance[v] {
+                continue;
+            }
+            settled[v] = true;
+            self.order.push(v);
+            for (&u, &weight) in dense.adjacency[v].iter().zip(&weights[v]) {
+                let candidate = distance + weight;
+                match candidate.total_cmp(&self.distance[u]) {
+                    Ordering::Less => {
+                        self.distance[u] = candidate;
+                        self.paths[u] = self.paths[v];
+                        self.predecessors[u] = vec![v];
+                        queue.push(Candidate(candidate, u));
+                    }
+                    Ordering::Equal if !settled[u] => {
+                        self.paths[u] += self.paths[v];
+                        self.predecessors[u].push(v);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+/// An entry of the Dijkstra's priority queue, ordered so that the closest item is popped first.
+struct Candidate(f64, usize);
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then(other.1.cmp(&self.1))
+    }
+}
@@ -92,3 +92,29 @@ fn adjacency_undirected_edges_can_be_bfs_traversed() {
     let traversal_2 = uedges(&[(1, 3), (3, 4), (1, 2), (4, 5)]);
     assert!(bfs_edges == traversal_1 || bfs_edges == traversal_2);
 }
+
+#[test]
+fn adjacency_directed_degrees_count_both_directions() {
+    let graph = create_directed_graph();
+    assert_eq!(graph.out_degree(1), Some(2));
+    assert_eq!(graph.in_degree(1), Some(0));
+    assert_eq!(graph.degree(3), Some(2));
+    assert_eq!(graph.in_degree(4), Some(1));
+    assert_eq!(graph.degree(10), None);
+    assert_eq!(graph.degree_histogram(), vec![0, 2, 2]);
+    assert_eq!(graph.average_degree(), 1.5);
+    assert_eq!(graph.density(), 3.0 / 12.0);
+}
+
+#[test]
+fn adjacency_undirected_degree_statistics() {
+    let graph = create_undirected_graph();
+    assert_eq!(graph.degree(1), Some(2));
+    assert_eq!(graph.in_degree(1), Some(2));
+    assert_eq!(graph.out_degree(5), Some(1));
+    assert_eq!(graph.degree_histogram(), vec![0, 2, 3]);
+    assert_eq!(graph.average_degree(), 8.0 / 5.0);
+    assert_eq!(graph.max_degree(), Some(2));
+    assert_eq!(graph.min_degree(), Some(1));
+    assert_eq!(graph.density(), 4.0 / 10.0);
+}
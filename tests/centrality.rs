@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use cinnabar::centrality::*;
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::prelude::*;
+use cinnabar::utils::{unweighted, UnorderedPair};
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn create_path(vertices: usize) -> AdjacencyList<usize, Undirected> {
+    let edges = (1..vertices).map(|v| (v - 1, v)).collect::<Vec<_>>();
+    create_graph(vertices, &edges)
+}
+
+fn create_star(leaves: usize) -> AdjacencyList<usize, Undirected> {
+    let edges = (1..=leaves).map(|v| (0, v)).collect::<Vec<_>>();
+    create_graph(leaves + 1, &edges)
+}
+
+fn assert_close(scores: &HashMap<usize, f64>, expected: &[f64]) {
+    for (id, value) in expected.iter().enumerate() {
+        let score = scores[&id];
+        assert!(
+            (score - value).abs() < 1e-9,
+            "{}: {} != {}",
+            id,
+            score,
+            value
+        );
+    }
+}
+
+#[test]
+fn degree_centrality() {
+    let graph = create_star(4);
+    assert_close(
+        &degree(&graph, unweighted(), false),
+        &[4.0, 1.0, 1.0, 1.0, 1.0],
+    );
+    assert_close(
+        &degree(&graph, unweighted(), true),
+        &[1.0, 0.25, 0.25, 0.25, 0.25],
+    );
+    let strength = degree(&graph, Some(|a: usize, b: usize| (a + b) as f64), false);
+    assert_close(&strength, &[10.0, 1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn closeness_centrality() {
+    let graph = create_star(4);
+    let leaf = 4.0 / 7.0;
+    assert_close(
+        &closeness(&graph, unweighted(), false),
+        &[1.0, leaf, leaf, leaf, leaf],
+    );
+
+    // Triangle where the direct link 0-2 is longer than the path through 1.
+    let graph = create_graph(3, &[(0, 1), (1, 2), (0, 2)]);
+    let length = |a: usize, b: usize| if a + b == 2 { 5.0 } else { 1.0 };
+    let scores = closeness(&graph, Some(length), false);
+    assert_close(&scores, &[2.0 / 3.0, 1.0, 2.0 / 3.0]);
+}
+
+#[test]
+fn closeness_is_scaled_in_disconnected_graphs() {
+    let graph = create_graph(4, &[(0, 1)]);
+    assert_close(
+        &closeness(&graph, unweighted(), false),
+        &[1.0, 1.0, 0.0, 0.0],
+    );
+    assert_close(
+        &closeness(&graph, unweighted(), true),
+        &[1.0 / 3.0, 1.0 / 3.0, 0.0, 0.0],
+    );
+}
+
+#[test]
+fn harmonic_centrality() {
+    let graph = create_path(5);
+    let end = 1.0 + 1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0;
+    let middle = 1.0 + 1.0 + 1.0 / 2.0 + 1.0 / 2.0;
+    let scores = harmonic(&graph, unweighted(), false);
+    assert_close(&scores, &[end, 1.0 + 1.0 + 0.5 + 1.0 / 3.0, middle]);
+    let scores = harmonic(&graph, unweighted(), true);
+    assert_close(&scores, &[end / 4.0]);
+}
+
+#[test]
+#[should_panic]
+fn harmonic_rejects_zero_lengths() {
+    let graph = create_path(3);
+    harmonic(&graph, Some(|_, _| 0.0), false);
+}
+
+#[test]
+#[should_panic]
+fn betweenness_rejects_negative_lengths() {
+    let graph = create_path(3);
+    betweenness(&graph, Some(|_, _| -1.0), false);
+}
+
+#[test]
+fn betweenness_centrality() {
+    let graph = create_path(5);
+    assert_close(
+        &betweenness(&graph, unweighted(), false),
+        &[0.0, 6.0, 8.0, 6.0, 0.0],
+    );
+    assert_close(
+        &betweenness(&graph, unweighted(), true),
+        &[0.0, 0.5, 2.0 / 3.0, 0.5, 0.0],
+    );
+
+    // A square has two shortest paths between opposite corners.
+    let graph = create_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    assert_close(
+        &betweenness(&graph, unweighted(), false),
+        &[1.0, 1.0, 1.0, 1.0],
+    );
+
+    let graph = create_graph(3, &[(0, 1), (1, 2), (0, 2)]);
+    let length = |a: usize, b: usize| if a + b == 2 { 5.0 } else { 1.0 };
+    let scores = betweenness(&graph, Some(length), false);
+    assert_close(&scores, &[0.0, 2.0, 0.0]);
+}
+
+#[test]
+fn betweenness_follows_edge_direction() {
+    let mut graph = AdjacencyList::<usize, Directed>::new();
+    for id in 0..3 {
+        graph.add(id);
+    }
+    graph.link(0, 1);
+    graph.link(1, 2);
+    assert_close(&betweenness(&graph, unweighted(), false), &[0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn edge_betweenness_centrality() {
+    let graph = create_path(3);
+    let scores = edge_betweenness(&graph, unweighted(), false);
+    assert_eq!(scores.len(), 2);
+    assert!((scores[&UnorderedPair(0, 1)] - 4.0).abs() < 1e-9);
+    assert!((scores[&UnorderedPair(2, 1)] - 4.0).abs() < 1e-9);
+
+    let graph = create_graph(3, &[(0, 1), (1, 2), (0, 2)]);
+    let length = |a: usize, b: usize| if a + b == 2 { 5.0 } else { 1.0 };
+    let scores = edge_betweenness(&graph, Some(length), true);
+    assert!((scores[&UnorderedPair(0, 1)] - 4.0 / 6.0).abs() < 1e-9);
+    assert!(scores[&UnorderedPair(0, 2)].abs() < 1e-9);
+}
+
+#[test]
+fn edge_betweenness_covers_grid_edges() {
+    let grid: Grid = Grid::new(3, 3);
+    let scores = edge_betweenness(&grid, unweighted(), true);
+    assert_eq!(scores.len(), grid.size());
+    let total = scores.values().sum::<f64>();
+    assert!(scores.values().all(|score| *score > 0.0));
+    // Every ordered pair contributes the average distance of 2 edges in a 3x3 grid.
+    assert!((total - 2.0).abs() < 1e-9);
+}
+
+fn create_directed_graph(
+    vertices: usize,
+    edges: &[(usize, usize)],
+) -> AdjacencyList<usize, Directed> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+#[test]
+fn pagerank_of_cycle_is_uniform() {
+    let graph = create_directed_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let ranking = pagerank(&graph, 0.85, 1e-10, 100);
+    assert!(ranking.converged);
+    assert_close(&ranking.scores, &[0.25, 0.25, 0.25, 0.25]);
+}
+
+#[test]
+fn pagerank_handles_dangling_vertices() {
+    // Vertex 2 has no outgoing edges, its rank is redistributed uniformly.
+    let graph = create_directed_graph(3, &[(0, 1), (0, 2), (1, 2)]);
+    let ranking = pagerank(&graph, 0.85, 1e-12, 1000);
+    assert!(ranking.converged);
+    let total = ranking.scores.values().sum::<f64>();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert!(ranking.scores[&2] > ranking.scores[&1]);
+    assert!(ranking.scores[&1] > ranking.scores[&0]);
+    // With the teleport term t, the ranks are t, 1.425 * t and 1.425 * 1.85 * t.
+    let t = 1.0 / (1.0 + 1.425 + 1.425 * 1.85);
+    assert_close(&ranking.scores, &[t, 1.425 * t, 1.425 * 1.85 * t]);
+}
+
+#[test]
+fn pagerank_reports_iterations() {
+    let graph = create_directed_graph(3, &[(0, 1), (0, 2), (1, 2)]);
+    let ranking = pagerank(&graph, 0.85, 1e-12, 3);
+    assert!(!ranking.converged);
+    assert_eq!(ranking.iterations, 3);
+}
+
+#[test]
+fn personalized_pagerank_favors_personalization() {
+    let graph = create_directed_graph(4, &[(0, 1), (1, 0), (2, 3), (3, 2)]);
+    let personalization = HashMap::from([(0, 1.0)]);
+    let ranking = personalized_pagerank(&graph, 0.85, 1e-12, 1000, &personalization);
+    assert!(ranking.converged);
+    assert!(ranking.scores[&2].abs() < 1e-9);
+    assert!(ranking.scores[&3].abs() < 1e-9);
+    assert!(ranking.scores[&0] > ranking.scores[&1]);
+}
+
+#[test]
+fn hits_separates_hubs_and_authorities() {
+    // Vertices 0 and 1 link to 2 and 3.
+    let graph = create_directed_graph(4, &[(0, 2), (0, 3), (1, 2), (1, 3)]);
+    let scores = hits(&graph, 1e-12, 100);
+    assert!(scores.converged);
+    assert_close(&scores.hubs, &[0.5, 0.5, 0.0, 0.0]);
+    assert_close(&scores.authorities, &[0.0, 0.0, 0.5, 0.5]);
+}
+
+#[test]
+fn katz_centrality_counts_attenuated_walks() {
+    // On a path 0 -> 1 -> 2, scores are beta, beta + alpha * beta, and so on.
+    let graph = create_directed_graph(3, &[(0, 1), (1, 2)]);
+    let ranking = katz(&graph, 0.5, 1.0, 1e-12, 100);
+    assert!(ranking.converged);
+    assert_close(&ranking.scores, &[1.0, 1.5, 1.75]);
+}
@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+
+use cinnabar::cliques::*;
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::prelude::*;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn sorted(cliques: impl Iterator<Item = Vec<usize>>) -> Vec<Vec<usize>> {
+    let mut cliques = cliques
+        .map(|mut clique| {
+            clique.sort_unstable();
+            clique
+        })
+        .collect::<Vec<_>>();
+    cliques.sort();
+    cliques
+}
+
+#[test]
+fn maximal_cliques_are_enumerated() {
+    // Two triangles sharing an edge, a pendant edge, and an isolated vertex.
+    let graph = create_graph(6, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    let cliques = sorted(maximal_cliques(&graph.vertices()));
+    assert_eq!(
+        cliques,
+        vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4], vec![5]]
+    );
+}
+
+#[test]
+fn maximal_cliques_of_complete_graph() {
+    let edges = (0..6)
+        .flat_map(|a| (a + 1..6).map(move |b| (a, b)))
+        .collect::<Vec<_>>();
+    let graph = create_graph(6, &edges);
+    let cliques = sorted(maximal_cliques(&graph.vertices()));
+    assert_eq!(cliques, vec![(0..6).collect::<Vec<_>>()]);
+}
+
+#[test]
+fn maximal_cliques_of_octahedron() {
+    // The octahedron is a complete graph without the perfect matching 0-1, 2-3, 4-5,
+    // so it has 8 triangular maximal cliques.
+    let edges = (0..6)
+        .flat_map(|a| (a + 1..6).map(move |b| (a, b)))
+        .filter(|(a, b)| !(a % 2 == 0 && *b == a + 1))
+        .collect::<Vec<_>>();
+    let graph = create_graph(6, &edges);
+    let cliques = sorted(maximal_cliques(&graph.vertices()));
+    assert_eq!(cliques.len(), 8);
+    assert!(cliques.iter().all(|clique| clique.len() == 3));
+}
+
+#[test]
+fn maximal_cliques_can_stop_early() {
+    let grid: Grid = Grid::new(30, 30);
+    let cliques = maximal_cliques(&grid.vertices())
+        .take(5)
+        .collect::<Vec<_>>();
+    assert_eq!(cliques.len(), 5);
+    assert!(cliques.iter().all(|clique| clique.len() == 2));
+    assert_eq!(maximal_cliques(&grid.vertices()).count(), grid.size());
+}
+
+#[test]
+fn max_clique_is_the_largest() {
+    let graph = create_graph(
+        7,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 4),
+            (3, 5),
+            (3, 6),
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (2, 3),
+        ],
+    );
+    let mut clique = max_clique(&graph.vertices());
+    clique.sort_unstable();
+    assert_eq!(clique, vec![3, 4, 5, 6]);
+    let empty = create_graph(0, &[]);
+    assert!(max_clique(&empty.vertices()).is_empty());
+}
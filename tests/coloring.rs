@@ -0,0 +1,134 @@
+use pretty_assertions::assert_eq;
+
+use cinnabar::coloring::*;
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::prelude::*;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn create_petersen_graph() -> AdjacencyList<usize, Undirected> {
+    let outer = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)];
+    let spokes = [(0, 5), (1, 6), (2, 7), (3, 8), (4, 9)];
+    let inner = [(5, 7), (7, 9), (9, 6), (6, 8), (8, 5)];
+    create_graph(10, &[outer, spokes, inner].concat())
+}
+
+/// Crown graph on `2 * n` vertices: `a_i` is linked to `b_j` for all `i != j`.
+fn create_crown_graph(n: usize) -> AdjacencyList<usize, Undirected> {
+    let mut edges = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if i != j {
+                edges.push((2 * i, 2 * j + 1));
+            }
+        }
+    }
+    create_graph(2 * n, &edges)
+}
+
+fn assert_proper<G: VertexProvider<usize>>(graph: &G, coloring: &Coloring<usize>) {
+    let vertices = graph.vertices();
+    assert_eq!(coloring.colors.len(), graph.order());
+    for id in vertices.iter() {
+        let color = coloring.colors[&id];
+        assert!(color < coloring.count);
+        for neighbor in vertices.adjacent_to(id).unwrap() {
+            assert_ne!(color, coloring.colors[&neighbor]);
+        }
+    }
+}
+
+#[test]
+fn greedy_coloring_follows_the_order() {
+    let graph = create_crown_graph(4);
+    let interleaved = greedy(&graph.vertices(), 0..8);
+    assert_proper(&graph, &interleaved);
+    assert_eq!(interleaved.count, 4);
+    let by_sides = greedy(&graph.vertices(), [0, 2, 4, 6, 1, 3, 5, 7]);
+    assert_proper(&graph, &by_sides);
+    assert_eq!(by_sides.count, 2);
+}
+
+#[test]
+fn greedy_coloring_skips_missing_vertices() {
+    let graph = create_graph(3, &[(0, 1), (1, 2)]);
+    let coloring = greedy(&graph.vertices(), [0, 2]);
+    assert_eq!(coloring.colors.len(), 2);
+    assert_eq!(coloring.count, 1);
+}
+
+#[test]
+fn welsh_powell_produces_proper_coloring() {
+    let graph = create_petersen_graph();
+    let coloring = welsh_powell(&graph.vertices());
+    assert_proper(&graph, &coloring);
+    assert!(coloring.count <= 4);
+}
+
+#[test]
+fn dsatur_colors_bipartite_graphs_with_two_colors() {
+    let graph = create_crown_graph(5);
+    let coloring = dsatur(&graph.vertices());
+    assert_proper(&graph, &coloring);
+    assert_eq!(coloring.count, 2);
+
+    let grid: Grid = Grid::new(4, 5);
+    let coloring = dsatur(&grid.vertices());
+    assert_eq!(coloring.count, 2);
+}
+
+#[test]
+fn dsatur_colors_odd_cycles_with_three_colors() {
+    let graph = create_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    let coloring = dsatur(&graph.vertices());
+    assert_proper(&graph, &coloring);
+    assert_eq!(coloring.count, 3);
+}
+
+#[test]
+fn exact_coloring_finds_chromatic_number() {
+    let graph = create_petersen_graph();
+    let coloring = exact(&graph.vertices(), 10_000).unwrap();
+    assert_proper(&graph, &coloring);
+    assert_eq!(coloring.count, 3);
+
+    let complete = (0..5)
+        .flat_map(|a| (a + 1..5).map(move |b| (a, b)))
+        .collect::<Vec<_>>();
+    let graph = create_graph(5, &complete);
+    let coloring = exact(&graph.vertices(), 10_000).unwrap();
+    assert_eq!(coloring.count, 5);
+}
+
+#[test]
+fn exact_coloring_respects_budget() {
+    // The Grötzsch graph is triangle-free but needs 4 colors, so the clique bound does not help.
+    let outer = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)];
+    let inner = [
+        (5, 1),
+        (5, 4),
+        (6, 0),
+        (6, 2),
+        (7, 1),
+        (7, 3),
+        (8, 2),
+        (8, 4),
+        (9, 3),
+        (9, 0),
+    ];
+    let center = [(10, 5), (10, 6), (10, 7), (10, 8), (10, 9)];
+    let graph = create_graph(11, &[&outer[..], &inner, &center].concat());
+    assert!(exact(&graph.vertices(), 1).is_none());
+    let coloring = exact(&graph.vertices(), 100_000).unwrap();
+    assert_proper(&graph, &coloring);
+    assert_eq!(coloring.count, 4);
+}
@@ -0,0 +1,133 @@
+use pretty_assertions::assert_eq;
+#[cfg(feature = "rand")]
+use rand::rngs::StdRng;
+#[cfg(feature = "rand")]
+use rand::SeedableRng;
+use std::collections::{HashMap, HashSet};
+
+use cinnabar::community::*;
+use cinnabar::graphs::AdjacencyList;
+use cinnabar::prelude::*;
+use cinnabar::utils::unweighted;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+/// A ring of `count` complete graphs on `size` vertices, consecutive cliques joined by an edge.
+fn create_ring_of_cliques(count: usize, size: usize) -> AdjacencyList<usize, Undirected> {
+    let mut edges = Vec::new();
+    for clique in 0..count {
+        let base = clique * size;
+        for a in 0..size {
+            for b in a + 1..size {
+                edges.push((base + a, base + b));
+            }
+        }
+        edges.push((base, (base + size + 1) % (count * size)));
+    }
+    create_graph(count * size, &edges)
+}
+
+fn assert_cliques_found(partition: &HashMap<usize, CommunityId>, count: usize, size: usize) {
+    assert_eq!(partition.len(), count * size);
+    let communities = partition.values().collect::<HashSet<_>>();
+    assert_eq!(communities.len(), count);
+    for v in 0..count * size {
+        assert_eq!(partition[&v], partition[&(v - v % size)]);
+    }
+}
+
+#[test]
+fn modularity_of_two_joined_cliques() {
+    let graph = create_ring_of_cliques(2, 4);
+    let partition = (0..8).map(|v| (v, v / 4)).collect::<HashMap<_, _>>();
+    // 14 edges: each community holds 6 of them and has total degree 14.
+    let expected = 2.0 * (12.0 / 28.0 - 0.25);
+    assert!((modularity(&graph, unweighted(), &partition) - expected).abs() < 1e-12);
+    let single = (0..8).map(|v| (v, 0)).collect::<HashMap<_, _>>();
+    assert!(modularity(&graph, unweighted(), &single).abs() < 1e-12);
+}
+
+#[test]
+fn modularity_treats_missing_vertices_as_singletons() {
+    let graph = create_graph(4, &[(0, 1), (2, 3)]);
+    let partition = HashMap::from([(0, 0), (1, 0)]);
+    let singletons = HashMap::from([(0, 0), (1, 0), (2, 1), (3, 2)]);
+    assert_eq!(
+        modularity(&graph, unweighted(), &partition),
+        modularity(&graph, unweighted(), &singletons)
+    );
+    let weighted = modularity(&graph, Some(|_, _| 2.0), &singletons);
+    assert!((weighted - modularity(&graph, unweighted(), &singletons)).abs() < 1e-12);
+}
+
+#[test]
+fn louvain_finds_ring_of_cliques() {
+    let graph = create_ring_of_cliques(6, 5);
+    let partition = louvain(&graph, unweighted(), 1.0);
+    assert_cliques_found(&partition, 6, 5);
+    assert!(modularity(&graph, unweighted(), &partition) > 0.7);
+}
+
+#[test]
+fn louvain_resolution_controls_community_size() {
+    let graph = create_ring_of_cliques(6, 5);
+    let coarse = louvain(&graph, unweighted(), 0.01);
+    assert_eq!(coarse.values().collect::<HashSet<_>>().len(), 1);
+}
+
+#[test]
+fn louvain_follows_heavy_edges() {
+    // A 4-cycle where edges 0-1 and 2-3 are heavy.
+    let graph = create_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let weight = |a: usize, b: usize| if a / 2 == b / 2 { 10.0 } else { 1.0 };
+    let partition = louvain(&graph, Some(weight), 1.0);
+    assert_eq!(partition[&0], partition[&1]);
+    assert_eq!(partition[&2], partition[&3]);
+    assert_ne!(partition[&0], partition[&2]);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn label_propagation_finds_disconnected_cliques() {
+    let mut graph = create_ring_of_cliques(3, 5);
+    graph.unlink(0, 6);
+    graph.unlink(5, 11);
+    graph.unlink(10, 1);
+    let mut rng = StdRng::seed_from_u64(7);
+    let partition = label_propagation(&graph, unweighted(), 100, &mut rng);
+    assert_cliques_found(&partition, 3, 5);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn label_propagation_is_reproducible() {
+    let graph = create_ring_of_cliques(8, 4);
+    let first = label_propagation(&graph, unweighted(), 100, &mut StdRng::seed_from_u64(42));
+    let second = label_propagation(&graph, unweighted(), 100, &mut StdRng::seed_from_u64(42));
+    assert_eq!(first, second);
+    let weighted = label_propagation(
+        &graph,
+        Some(|_, _| 1.0),
+        100,
+        &mut StdRng::seed_from_u64(42),
+    );
+    assert_eq!(first, weighted);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn label_propagation_stops_after_max_iterations() {
+    let graph = create_ring_of_cliques(4, 4);
+    let partition = label_propagation(&graph, unweighted(), 0, &mut StdRng::seed_from_u64(1));
+    // Without any pass, every vertex keeps a label of its own.
+    assert_eq!(partition.values().collect::<HashSet<_>>().len(), 16);
+}
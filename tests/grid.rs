@@ -1,8 +1,8 @@
 use pretty_assertions::assert_eq;
 use std::collections::HashSet;
 
-use cinnabar::graphs::grid::{Edge, EdgeSet};
-use cinnabar::graphs::Grid;
+use cinnabar::graphs::grid::{Coords, Edge, EdgeSet, Neighborhood, Wrapping};
+use cinnabar::graphs::{DenseGrid, Grid};
 use cinnabar::prelude::*;
 use cinnabar::traversal::*;
 
@@ -96,3 +96,558 @@ fn grid_edges_can_be_bfs_traversed() {
     let all_edges = grid.edges().iter().collect::<EdgeSet<_>>();
     assert_eq!(dfs_edges, all_edges);
 }
+
+#[test]
+fn grid_degrees_depend_on_position() {
+    let grid = create_grid();
+    assert_eq!(grid.degree(grid.at(0, 0).unwrap()), Some(2));
+    assert_eq!(grid.degree(grid.at(0, 1).unwrap()), Some(3));
+    assert_eq!(grid.degree(grid.at(1, 1).unwrap()), Some(4));
+    assert_eq!(grid.in_degree(grid.at(2, 3).unwrap()), Some(2));
+    assert_eq!(grid.degree_histogram(), vec![0, 0, 4, 6, 2]);
+    assert_eq!(grid.max_degree(), Some(4));
+    assert_eq!(grid.min_degree(), Some(2));
+    let total = 2 * grid.size();
+    assert_eq!(grid.average_degree(), total as f64 / grid.order() as f64);
+    assert_eq!(grid.density(), grid.size() as f64 / 66.0);
+}
+
+#[test]
+fn grid_cells_can_be_removed_and_restored() {
+    let mut grid = create_grid();
+    let full_size = grid.size();
+    let center = grid.at(1, 1).unwrap();
+    assert!(grid.remove(center));
+    assert!(!grid.remove(center));
+    assert_eq!(grid.at(1, 1), None);
+    assert!(grid.coords_of(center).is_none());
+    assert_eq!(grid.order(), ROWS * COLS - 1);
+    assert_eq!(grid.size(), full_size - 4);
+    assert_eq!(grid.edges().iter().count(), grid.size());
+    assert!(!grid.vertices().contains(center));
+    assert_eq!(grid.degree(grid.at(0, 1).unwrap()), Some(2));
+    assert!(!grid
+        .edges()
+        .contains(Edge::new(center, grid.at(0, 1).unwrap())));
+    assert_eq!(grid.traverse_by_rows().count(), ROWS * COLS - 1);
+
+    assert!(grid.add(center));
+    assert!(!grid.add(center));
+    assert_eq!(grid.at(1, 1), Some(center));
+    assert_eq!(grid.size(), full_size);
+}
+
+#[test]
+fn grid_walls_cut_edges() {
+    let mut grid = create_grid();
+    let full_size = grid.size();
+    let a = grid.at(0, 0).unwrap();
+    let b = grid.at(0, 1).unwrap();
+    let far = grid.at(2, 3).unwrap();
+    assert!(!grid.unlink(a, far));
+    assert!(!grid.link(a, b));
+    assert!(grid.unlink(b, a));
+    assert!(!grid.unlink(a, b));
+    assert_eq!(grid.size(), full_size - 1);
+    assert!(!grid.edges().contains(Edge::new(a, b)));
+    assert!(!grid.edges().iter().any(|edge| edge == Edge::new(a, b)));
+    let neighbors = grid.vertices().adjacent_to(a).unwrap().collect::<Vec<_>>();
+    assert_eq!(neighbors, vec![grid.at(1, 0).unwrap()]);
+
+    // The grid stays connected around the wall.
+    assert_eq!(bfs(&grid.vertices(), a).count(), ROWS * COLS);
+    assert!(grid.link(a, b));
+    assert_eq!(grid.size(), full_size);
+    assert!(grid.edges().contains(Edge::new(a, b)));
+}
+
+#[test]
+fn grid_walls_and_removed_cells_split_grid() {
+    let mut grid = create_grid();
+    // Block the second column except for its top cell, and wall that cell off.
+    for row in 1..ROWS {
+        let id = grid.at(row, 1).unwrap();
+        grid.remove(id);
+    }
+    grid.unlink(grid.at(0, 0).unwrap(), grid.at(0, 1).unwrap());
+    let start = grid.at(0, 0).unwrap();
+    let reachable = dfs(&grid.vertices(), start).collect::<HashSet<_>>();
+    assert_eq!(reachable.len(), ROWS);
+    let edges = grid.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(edges.len(), grid.size());
+    assert_eq!(
+        dfs(&grid.edges(), Edge::new(start, grid.at(1, 0).unwrap())).count(),
+        2
+    );
+}
+
+#[test]
+fn grid_supports_moore_neighborhood() {
+    let grid = create_grid().with_neighborhood(Neighborhood::Moore);
+    let diagonals = 2 * (ROWS - 1) * (COLS - 1);
+    assert_eq!(
+        grid.size(),
+        ROWS * (COLS - 1) + COLS * (ROWS - 1) + diagonals
+    );
+    assert_eq!(grid.edges().iter().count(), grid.size());
+    assert_eq!(grid.degree(grid.at(0, 0).unwrap()), Some(3));
+    assert_eq!(grid.degree(grid.at(1, 1).unwrap()), Some(8));
+    let a = grid.at(0, 0).unwrap();
+    let b = grid.at(1, 1).unwrap();
+    assert!(grid.edges().contains(Edge::new(a, b)));
+    assert!(grid.vertices().adjacent_to(a).unwrap().any(|id| id == b));
+}
+
+#[test]
+fn grid_supports_knight_and_custom_neighborhoods() {
+    let grid = create_grid().with_neighborhood(Neighborhood::Knight);
+    let corner = grid.at(0, 0).unwrap();
+    let mut neighbors = grid
+        .vertices()
+        .adjacent_to(corner)
+        .unwrap()
+        .map(|id| grid.coords_of(id).unwrap())
+        .collect::<Vec<_>>();
+    neighbors.sort_by_key(|Coords(row, col)| (*row, *col));
+    assert_eq!(neighbors, vec![Coords(1, 2), Coords(2, 1)]);
+    assert_eq!(grid.edges().iter().count(), grid.size());
+    let edges = grid.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(edges.len(), grid.size());
+
+    // Rook-like jumps by two columns split every row into two components.
+    let grid = create_grid().with_neighborhood(Neighborhood::Custom(vec![(0, 2)]));
+    assert_eq!(grid.size(), ROWS * (COLS - 2));
+    let start = grid.at(0, 0).unwrap();
+    let reachable = bfs(&grid.vertices(), start)
+        .map(|id| grid.coords_of(id).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(reachable, vec![Coords(0, 0), Coords(0, 2)]);
+}
+
+#[test]
+fn grid_can_forbid_corner_cutting() {
+    let mut grid = create_grid()
+        .with_neighborhood(Neighborhood::Moore)
+        .with_corner_cutting(false);
+    let full_size = grid.size();
+    let a = grid.at(0, 0).unwrap();
+    let b = grid.at(1, 1).unwrap();
+    assert!(grid.edges().contains(Edge::new(a, b)));
+    grid.remove(grid.at(0, 1).unwrap());
+    assert!(!grid.edges().contains(Edge::new(a, b)));
+    assert!(!grid.vertices().adjacent_to(b).unwrap().any(|id| id == a));
+    // Three side edges and two diagonals of the removed cell, plus the cut corners 0,0-1,1
+    // and 0,2-1,1.
+    assert_eq!(grid.size(), full_size - 5 - 2);
+    assert_eq!(grid.edges().iter().count(), grid.size());
+}
+
+#[test]
+fn grid_can_wrap_around() {
+    let grid = create_grid().with_wrapping(Wrapping::Both);
+    assert_eq!(grid.size(), 2 * ROWS * COLS);
+    assert_eq!(
+        grid.edges().iter().collect::<EdgeSet<_>>().len(),
+        grid.size()
+    );
+    assert!(grid.vertices().iter().all(|id| grid.degree(id) == Some(4)));
+    let corner = grid.at(0, 0).unwrap();
+    let mut neighbors = grid.neighbors_of(0, 0).collect::<Vec<_>>();
+    neighbors.sort_by_key(|Coords(row, col)| (*row, *col));
+    assert_eq!(
+        neighbors,
+        vec![
+            Coords(0, 1),
+            Coords(0, COLS - 1),
+            Coords(1, 0),
+            Coords(ROWS - 1, 0)
+        ]
+    );
+    let opposite = grid.at(0, COLS - 1).unwrap();
+    assert!(grid.edges().contains(Edge::new(corner, opposite)));
+
+    let grid = create_grid().with_wrapping(Wrapping::Horizontal);
+    assert_eq!(grid.size(), ROWS * COLS + COLS * (ROWS - 1));
+    assert_eq!(grid.edges().iter().count(), grid.size());
+    let corner = grid.at(0, 0).unwrap();
+    assert!(!grid
+        .edges()
+        .contains(Edge::new(corner, grid.at(ROWS - 1, 0).unwrap())));
+    assert!(grid
+        .edges()
+        .contains(Edge::new(corner, grid.at(0, COLS - 1).unwrap())));
+}
+
+#[test]
+fn narrow_grids_wrap_without_loops_and_duplicates() {
+    // A single row of two cells: both horizontal steps lead to the same neighbor, and vertical
+    // steps lead back to the cell itself.
+    let grid: Grid = Grid::new(1, 2).with_wrapping(Wrapping::Both);
+    assert_eq!(grid.size(), 1);
+    assert_eq!(grid.edges().iter().count(), 1);
+    assert_eq!(
+        grid.neighbors_of(0, 0).collect::<Vec<_>>(),
+        vec![Coords(0, 1)]
+    );
+    assert_eq!(grid.degree(grid.at(0, 1).unwrap()), Some(1));
+
+    let grid: Grid = Grid::new(1, 1).with_wrapping(Wrapping::Both);
+    assert_eq!(grid.size(), 0);
+    assert_eq!(grid.neighbors_of(0, 0).count(), 0);
+
+    // A 2x2 torus is a square, and the 8-way neighborhood makes it complete.
+    let grid: Grid = Grid::new(2, 2).with_wrapping(Wrapping::Both);
+    assert_eq!(grid.size(), 4);
+    let grid = grid.with_neighborhood(Neighborhood::Moore);
+    assert_eq!(grid.size(), 6);
+    assert_eq!(grid.edges().iter().collect::<EdgeSet<_>>().len(), 6);
+}
+
+#[test]
+fn wrapped_grid_supports_walls() {
+    let mut grid = create_grid().with_wrapping(Wrapping::Vertical);
+    let top = grid.at(0, 2).unwrap();
+    let bottom = grid.at(ROWS - 1, 2).unwrap();
+    assert!(grid.unlink(top, bottom));
+    assert!(!grid.edges().contains(Edge::new(top, bottom)));
+    assert_eq!(grid.degree(top), Some(3));
+    assert_eq!(grid.edges().iter().count(), grid.size());
+    assert!(grid.link(bottom, top));
+    assert_eq!(grid.degree(top), Some(4));
+}
+
+#[test]
+fn dense_grid_uses_arithmetic_indices() {
+    let grid = DenseGrid::new(ROWS, COLS);
+    assert_eq!(grid.order(), ROWS * COLS);
+    assert_eq!(grid.at(1, 2), Some(COLS + 2));
+    assert_eq!(grid.at(ROWS, 0), None);
+    assert_eq!(grid.at(0, COLS), None);
+    assert_eq!(grid.coords_of(COLS + 2), Some(Coords(1, 2)));
+    assert_eq!(grid.coords_of(ROWS * COLS), None);
+    assert_eq!(
+        grid.vertices().iter().collect::<Vec<_>>(),
+        (0..ROWS * COLS).collect::<Vec<_>>()
+    );
+    assert!(grid.vertices().contains(ROWS * COLS - 1));
+    assert!(!grid.vertices().contains(ROWS * COLS));
+}
+
+#[test]
+fn dense_grid_matches_grid() {
+    let configurations = [
+        (Neighborhood::VonNeumann, Wrapping::None),
+        (Neighborhood::Moore, Wrapping::None),
+        (Neighborhood::Knight, Wrapping::Both),
+        (Neighborhood::VonNeumann, Wrapping::Vertical),
+    ];
+    for (neighborhood, wrapping) in configurations {
+        let dense = DenseGrid::new(ROWS, COLS)
+            .with_neighborhood(neighborhood.clone())
+            .with_wrapping(wrapping);
+        let grid = create_grid()
+            .with_neighborhood(neighborhood)
+            .with_wrapping(wrapping);
+        assert_eq!(dense.size(), grid.size());
+        assert_eq!(
+            dense.edges().iter().collect::<EdgeSet<_>>().len(),
+            dense.size()
+        );
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                let id = dense.at(row, col).unwrap();
+                assert_eq!(dense.degree(id), grid.degree(grid.at(row, col).unwrap()));
+            }
+        }
+    }
+}
+
+#[test]
+fn dense_grid_can_be_traversed() {
+    let grid = DenseGrid::new(ROWS, COLS);
+    assert_eq!(dfs(&grid.vertices(), 0).count(), ROWS * COLS);
+    assert_eq!(bfs(&grid.vertices(), 5).count(), ROWS * COLS);
+    let edges = grid.edges();
+    assert!(edges.contains(Edge::new(0, 1)));
+    assert!(edges.contains(Edge::new(COLS, 0)));
+    assert!(!edges.contains(Edge::new(COLS - 1, COLS)));
+    let dfs_edges = dfs(&edges, Edge::new(0, 1)).collect::<EdgeSet<_>>();
+    assert_eq!(dfs_edges, edges.iter().collect::<EdgeSet<_>>());
+}
+
+fn coords_of_order(grid: &Grid, order: impl Iterator<Item = Counter>) -> Vec<(usize, usize)> {
+    order
+        .map(|id| {
+            let Coords(row, col) = grid.coords_of(id).unwrap();
+            (row, col)
+        })
+        .collect()
+}
+
+#[test]
+fn grid_traversal_orders() {
+    let grid = create_grid();
+    assert_eq!(
+        coords_of_order(&grid, grid.traverse_by_columns())[..4],
+        [(0, 0), (1, 0), (2, 0), (0, 1)]
+    );
+    assert_eq!(
+        coords_of_order(&grid, grid.traverse_serpentine())[3..6],
+        [(0, 3), (1, 3), (1, 2)]
+    );
+    let spiral = coords_of_order(&grid, grid.traverse_spiral_inward());
+    assert_eq!(
+        spiral,
+        [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (1, 0),
+            (1, 1),
+            (1, 2)
+        ]
+    );
+    let mut outward = coords_of_order(&grid, grid.traverse_spiral_outward());
+    outward.reverse();
+    assert_eq!(outward, spiral);
+    assert_eq!(
+        coords_of_order(&grid, grid.traverse_by_diagonals())[..6],
+        [(0, 0), (0, 1), (1, 0), (0, 2), (1, 1), (2, 0)]
+    );
+    assert_eq!(
+        coords_of_order(&grid, grid.traverse_morton())[..6],
+        [(0, 0), (0, 1), (1, 0), (1, 1), (0, 2), (0, 3)]
+    );
+}
+
+#[test]
+fn grid_traversals_visit_every_cell_once() {
+    for (rows, columns) in [
+        (0, 3),
+        (1, 1),
+        (1, 5),
+        (4, 1),
+        (3, 4),
+        (5, 5),
+        (6, 9),
+        (8, 8),
+    ] {
+        let grid: Grid = Grid::new(rows, columns);
+        let orders: [Vec<Counter>; 8] = [
+            grid.traverse_by_rows().collect(),
+            grid.traverse_by_columns().collect(),
+            grid.traverse_serpentine().collect(),
+            grid.traverse_spiral_inward().collect(),
+            grid.traverse_spiral_outward().collect(),
+            grid.traverse_by_diagonals().collect(),
+            grid.traverse_hilbert().collect(),
+            grid.traverse_morton().collect(),
+        ];
+        for order in orders {
+            assert_eq!(order.len(), rows * columns);
+            assert_eq!(order.iter().collect::<HashSet<_>>().len(), rows * columns);
+        }
+    }
+}
+
+#[test]
+fn hilbert_traversal_is_continuous() {
+    for (rows, columns) in [(8, 8), (4, 6), (5, 9), (16, 3)] {
+        let grid: Grid = Grid::new(rows, columns).with_neighborhood(Neighborhood::Moore);
+        let order = grid.traverse_hilbert().collect::<Vec<_>>();
+        let steps = order.windows(2).filter(|pair| {
+            let (a, b) = (
+                grid.coords_of(pair[0]).unwrap(),
+                grid.coords_of(pair[1]).unwrap(),
+            );
+            a.0 != b.0 && a.1 != b.1
+        });
+        let edges = grid.edges();
+        assert!(order
+            .windows(2)
+            .all(|pair| edges.contains(Edge::new(pair[0], pair[1]))));
+        assert!(steps.count() <= 1);
+    }
+}
+
+#[test]
+fn grid_traversals_skip_removed_cells() {
+    let mut grid = create_grid();
+    let removed = grid.at(1, 1).unwrap();
+    grid.remove(removed);
+    assert_eq!(grid.traverse_hilbert().count(), ROWS * COLS - 1);
+    assert!(grid.traverse_spiral_outward().all(|id| id != removed));
+}
+
+#[test]
+fn dense_grid_traversal_orders() {
+    let grid = DenseGrid::new(ROWS, COLS);
+    assert_eq!(
+        grid.traverse_by_columns().take(4).collect::<Vec<_>>(),
+        [0, COLS, 2 * COLS, 1]
+    );
+    assert_eq!(
+        grid.traverse_spiral_outward().take(2).collect::<Vec<_>>(),
+        [COLS + 2, COLS + 1]
+    );
+    assert_eq!(
+        grid.traverse_morton().take(4).collect::<Vec<_>>(),
+        [0, 1, COLS, COLS + 1]
+    );
+    let mut hilbert = grid.traverse_hilbert().collect::<Vec<_>>();
+    hilbert.sort();
+    assert_eq!(hilbert, grid.traverse_by_rows().collect::<Vec<_>>());
+}
+
+#[test]
+fn region_is_a_graph_on_its_own() {
+    let grid: Grid = Grid::new(5, 6);
+    let region = grid.region(1..4, 2..);
+    assert_eq!((region.rows(), region.columns()), (3, 4));
+    assert_eq!(region.origin(), Coords(1, 2));
+    assert_eq!(region.order(), 12);
+    assert_eq!(region.size(), 3 * 3 + 2 * 4);
+    assert_eq!(
+        region.edges().iter().collect::<EdgeSet<_>>().len(),
+        region.size()
+    );
+
+    let corner = region.at(0, 0).unwrap();
+    assert_eq!(Some(corner), grid.at(1, 2));
+    assert_eq!(region.coords_of(corner), Some(Coords(0, 0)));
+    assert_eq!(region.coords_of(grid.at(0, 0).unwrap()), None);
+    assert_eq!(region.at(3, 0), None);
+    assert_eq!(region.degree(corner), Some(2));
+    assert_eq!(grid.degree(corner), Some(4));
+
+    let outside = grid.at(0, 2).unwrap();
+    assert!(!region.edges().contains(Edge::new(corner, outside)));
+    assert!(region
+        .edges()
+        .contains(Edge::new(corner, region.at(0, 1).unwrap())));
+    assert_eq!(dfs(&region.vertices(), corner).count(), 12);
+    assert!(dfs(&region.vertices(), corner).all(|id| region.coords_of(id).is_some()));
+}
+
+#[test]
+fn region_respects_obstacles_and_wrapping() {
+    let mut grid: Grid = Grid::new(4, 4).with_wrapping(Wrapping::Both);
+    let removed = grid.at(1, 1).unwrap();
+    grid.remove(removed);
+    let region = grid.region(.., 0..2);
+    assert_eq!(region.order(), 7);
+    assert_eq!(region.at(1, 1), None);
+    // Wrapped edges inside the window are kept, and the ones leaving it are excluded.
+    let top = region.at(0, 0).unwrap();
+    let bottom = region.at(3, 0).unwrap();
+    assert!(region.edges().contains(Edge::new(top, bottom)));
+    assert_eq!(region.degree(top), Some(3));
+    assert_eq!(region.size(), region.edges().iter().count());
+}
+
+#[test]
+#[should_panic]
+fn region_must_fit_in_grid() {
+    let grid = create_grid();
+    grid.region(0..ROWS + 1, ..);
+}
+
+#[test]
+fn windows_slide_over_grid() {
+    let grid = create_grid();
+    let windows = grid.windows(2, 3).collect::<Vec<_>>();
+    assert_eq!(windows.len(), 2 * 2);
+    let origins = windows.iter().map(|w| w.origin()).collect::<Vec<_>>();
+    assert_eq!(
+        origins,
+        [Coords(0, 0), Coords(0, 1), Coords(1, 0), Coords(1, 1)]
+    );
+    assert!(windows.iter().all(|w| w.order() == 6 && w.size() == 7));
+    assert_eq!(windows[3].at(0, 0), grid.at(1, 1));
+    assert_eq!(grid.windows(ROWS + 1, 1).count(), 0);
+    assert_eq!(grid.windows(1, 1).count(), ROWS * COLS);
+}
+
+#[test]
+fn bresenham_lines() {
+    let grid = create_grid();
+    let line = grid.line(Coords(0, 0), Coords(2, 3)).collect::<Vec<_>>();
+    assert_eq!(
+        line,
+        [Coords(0, 0), Coords(1, 1), Coords(1, 2), Coords(2, 3)]
+    );
+    let reversed = grid.line(Coords(2, 3), Coords(0, 0)).count();
+    assert_eq!(reversed, 4);
+    let point = grid.line(Coords(1, 1), Coords(1, 1)).collect::<Vec<_>>();
+    assert_eq!(point, [Coords(1, 1)]);
+    let column = grid.line(Coords(2, 0), Coords(0, 0)).collect::<Vec<_>>();
+    assert_eq!(column, [Coords(2, 0), Coords(1, 0), Coords(0, 0)]);
+}
+
+#[test]
+fn line_of_sight_is_blocked_by_opaque_cells() {
+    let mut grid = create_grid();
+    let pillar = grid.at(1, 1).unwrap();
+    let opaque = |id| id == pillar;
+    assert!(!grid.has_line_of_sight(Coords(0, 0), Coords(2, 2), opaque));
+    assert!(grid.has_line_of_sight(Coords(0, 0), Coords(1, 1), opaque));
+    assert!(grid.has_line_of_sight(Coords(0, 0), Coords(0, 3), opaque));
+    assert!(!grid.has_line_of_sight(Coords(2, 0), Coords(0, 3), opaque));
+    assert!(grid.has_line_of_sight(Coords(2, 0), Coords(2, 3), opaque));
+
+    let removed = grid.at(0, 1).unwrap();
+    grid.remove(removed);
+    assert!(!grid.has_line_of_sight(Coords(0, 0), Coords(0, 3), |_| false));
+    assert!(!grid.has_line_of_sight(Coords(0, 0), Coords(0, 1), |_| false));
+    assert!(!grid.has_line_of_sight(Coords(0, 0), Coords(ROWS, 0), |_| false));
+}
+
+#[test]
+fn field_of_view_with_shadows() {
+    // A wall in the middle of the row above the origin casts a shadow upwards.
+    let grid: Grid = Grid::new(7, 7);
+    let wall = grid.at(2, 3).unwrap();
+    let opaque = |id| id == wall;
+    let visible = grid.field_of_view(Coords(3, 3), 10, opaque);
+    assert!(visible.contains(&grid.at(3, 3).unwrap()));
+    assert!(visible.contains(&wall));
+    assert!(!visible.contains(&grid.at(1, 3).unwrap()));
+    assert!(!visible.contains(&grid.at(0, 3).unwrap()));
+    assert!(!visible.contains(&grid.at(0, 2).unwrap()));
+    assert!(visible.contains(&grid.at(1, 2).unwrap()));
+    assert!(visible.contains(&grid.at(0, 0).unwrap()));
+    assert!(visible.contains(&grid.at(6, 6).unwrap()));
+    assert_eq!(visible.len(), 49 - 4);
+
+    // Visibility is symmetric between transparent cells.
+    for row in 0..7 {
+        for col in 0..7 {
+            let id = grid.at(row, col).unwrap();
+            if id == wall {
+                continue;
+            }
+            let back = grid.field_of_view(Coords(row, col), 10, opaque);
+            assert_eq!(
+                visible.contains(&id),
+                back.contains(&grid.at(3, 3).unwrap())
+            );
+        }
+    }
+}
+
+#[test]
+fn field_of_view_is_limited_by_radius() {
+    let grid: Grid = Grid::new(9, 9);
+    let visible = grid.field_of_view(Coords(4, 4), 2, |_| false);
+    assert_eq!(visible.len(), 13);
+    assert!(visible.contains(&grid.at(2, 4).unwrap()));
+    assert!(!visible.contains(&grid.at(2, 3).unwrap()));
+    assert_eq!(grid.field_of_view(Coords(4, 4), 0, |_| false).len(), 1);
+    assert!(grid.field_of_view(Coords(9, 0), 5, |_| false).is_empty());
+}
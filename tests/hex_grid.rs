@@ -0,0 +1,129 @@
+use pretty_assertions::assert_eq;
+use std::collections::HashSet;
+
+use cinnabar::graphs::hex_grid::{Axial, Edge, EdgeSet, Orientation, Shape};
+use cinnabar::graphs::HexGrid;
+use cinnabar::prelude::*;
+use cinnabar::traversal::*;
+
+fn create_hexagon(radius: usize) -> HexGrid<Counter> {
+    HexGrid::new(Shape::Hexagon { radius }, Orientation::Pointy)
+}
+
+#[test]
+fn hexagon_has_correct_order_and_size() {
+    let grid = create_hexagon(2);
+    assert_eq!(grid.order(), 19);
+    // A hexagon of radius n has 3n(3n + 1) edges.
+    assert_eq!(grid.size(), 42);
+    let edges = grid.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(edges.len(), grid.size());
+    assert_eq!(grid.degree(grid.at(Axial(0, 0)).unwrap()), Some(6));
+    assert_eq!(grid.degree(grid.at(Axial(2, 0)).unwrap()), Some(3));
+    assert_eq!(grid.degree(grid.at(Axial(1, 1)).unwrap()), Some(4));
+    let corner = grid.at(Axial(2, 0)).unwrap();
+    assert_eq!(grid.out_degree(corner), Some(3));
+    assert_eq!(grid.in_degree(corner), Some(3));
+}
+
+#[test]
+fn shapes_have_expected_cells() {
+    for orientation in [Orientation::Pointy, Orientation::Flat] {
+        let grid: HexGrid = HexGrid::new(
+            Shape::Rectangle {
+                rows: 3,
+                columns: 4,
+            },
+            orientation,
+        );
+        assert_eq!(grid.order(), 12);
+        assert_eq!(grid.orientation(), orientation);
+        let start = grid.at(Axial(0, 0)).unwrap();
+        assert_eq!(bfs(&grid.vertices(), start).count(), 12);
+    }
+    let grid: HexGrid = HexGrid::new(
+        Shape::Rectangle {
+            rows: 3,
+            columns: 4,
+        },
+        Orientation::Pointy,
+    );
+    // The third row is shifted back by one step along the q axis.
+    assert!(grid.at(Axial(-1, 2)).is_some());
+    assert!(grid.at(Axial(3, 2)).is_none());
+
+    let grid: HexGrid = HexGrid::new(
+        Shape::Rhombus {
+            width: 3,
+            height: 2,
+        },
+        Orientation::Flat,
+    );
+    assert_eq!(grid.order(), 6);
+    // Two rows of two horizontal edges, and each upper cell touches up to two lower cells.
+    assert_eq!(grid.size(), 4 + 5);
+}
+
+#[test]
+fn coordinates_and_neighbors() {
+    let grid = create_hexagon(1);
+    let mut ids = HashSet::new();
+    for hex in Axial(0, 0).spiral(1) {
+        let id = grid.at(hex).unwrap();
+        assert_eq!(grid.coords_of(id), Some(hex));
+        ids.insert(id);
+    }
+    assert_eq!(ids.len(), 7);
+    assert_eq!(grid.neighbors_of(Axial(0, 0)).count(), 6);
+    assert_eq!(grid.neighbors_of(Axial(1, 0)).count(), 3);
+    let a = grid.at(Axial(1, 0)).unwrap();
+    let b = grid.at(Axial(0, 1)).unwrap();
+    let c = grid.at(Axial(-1, 0)).unwrap();
+    assert!(grid.edges().contains(Edge::new(a, b)));
+    assert!(!grid.edges().contains(Edge::new(a, c)));
+    assert_eq!(grid.distance(a, c), Some(2));
+}
+
+#[test]
+fn hex_distance_rings_and_spirals() {
+    let center = Axial(1, -2);
+    assert_eq!(center.distance(Axial(4, -2)), 3);
+    assert_eq!(center.distance(Axial(-1, 1)), 3);
+    assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+    for radius in 1..4 {
+        let ring = center.ring(radius).collect::<Vec<_>>();
+        assert_eq!(ring.len(), 6 * radius);
+        assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+        // Consecutive hexagons of a ring are neighbors.
+        for k in 0..ring.len() {
+            assert_eq!(ring[k].distance(ring[(k + 1) % ring.len()]), 1);
+        }
+    }
+    let spiral = center.spiral(3).collect::<Vec<_>>();
+    assert_eq!(spiral.len(), 37);
+    assert_eq!(spiral.iter().collect::<HashSet<_>>().len(), 37);
+
+    let grid = create_hexagon(2);
+    assert_eq!(grid.ring(Axial(2, 0), 1).count(), 3);
+    assert_eq!(grid.spiral(Axial(0, 0), 5).count(), 19);
+}
+
+#[test]
+fn hex_edges_can_be_traversed() {
+    let grid = create_hexagon(2);
+    let a = grid.at(Axial(0, 0)).unwrap();
+    let b = grid.at(Axial(1, 0)).unwrap();
+    let dfs_edges = dfs(&grid.edges(), Edge::new(a, b)).collect::<EdgeSet<_>>();
+    let all_edges = grid.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(dfs_edges, all_edges);
+}
+
+#[test]
+fn pixel_centers_follow_orientation() {
+    let pointy: HexGrid = HexGrid::new(Shape::Hexagon { radius: 1 }, Orientation::Pointy);
+    let (x, y) = pointy.center_of(Axial(1, 0), 1.0);
+    assert!((x - 3f64.sqrt()).abs() < 1e-9 && y.abs() < 1e-9);
+    let flat: HexGrid = HexGrid::new(Shape::Hexagon { radius: 1 }, Orientation::Flat);
+    let (x, y) = flat.center_of(Axial(0, 1), 1.0);
+    assert!(x.abs() < 1e-9 && (y - 3f64.sqrt()).abs() < 1e-9);
+}
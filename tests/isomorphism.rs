@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+
+use cinnabar::graphs::grid::Coords;
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::isomorphism::*;
+use cinnabar::prelude::*;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn create_directed_graph(
+    vertices: usize,
+    edges: &[(usize, usize)],
+) -> AdjacencyList<usize, Directed> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn assert_preserves_edges<I, A, B>(a: &A, b: &B, mapping: &HashMap<usize, I>)
+where
+    I: Index,
+    A: VertexProvider<usize>,
+    B: VertexProvider<I>,
+{
+    let (a, b) = (a.vertices(), b.vertices());
+    for (&v, &u) in mapping {
+        for (&w, &image) in mapping {
+            let expected = a.adjacent_to(v).unwrap().any(|x| x == w);
+            assert_eq!(b.adjacent_to(u).unwrap().any(|x| x == image), expected);
+        }
+    }
+}
+
+#[test]
+fn relabeled_graphs_are_isomorphic() {
+    let a = create_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 2)]);
+    let b = create_graph(5, &[(3, 1), (1, 4), (4, 0), (0, 2), (2, 3), (3, 4)]);
+    let mapping = is_isomorphic(&a, &b).unwrap();
+    assert_eq!(mapping.len(), 5);
+    assert_eq!(mapping.values().collect::<HashSet<_>>().len(), 5);
+    assert_preserves_edges(&a, &b, &mapping);
+}
+
+#[test]
+fn graphs_with_same_degrees_can_differ() {
+    // A hexagon and two triangles are both 2-regular on six vertices.
+    let hexagon = create_graph(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+    let triangles = create_graph(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert!(is_isomorphic(&hexagon, &triangles).is_none());
+    assert!(is_isomorphic(&hexagon, &hexagon).is_some());
+}
+
+#[test]
+fn isomorphism_respects_direction() {
+    let a = create_directed_graph(3, &[(0, 1), (1, 2)]);
+    let b = create_directed_graph(3, &[(2, 1), (1, 0)]);
+    let c = create_directed_graph(3, &[(1, 0), (1, 2)]);
+    let mapping = is_isomorphic(&a, &b).unwrap();
+    assert_eq!(mapping, HashMap::from([(0, 2), (1, 1), (2, 0)]));
+    assert!(is_isomorphic(&a, &c).is_none());
+}
+
+#[test]
+fn grid_matches_adjacency_list() {
+    let grid: Grid = Grid::new(2, 3);
+    let ladder = create_graph(6, &[(0, 1), (1, 2), (3, 4), (4, 5), (0, 3), (1, 4), (2, 5)]);
+    let mapping = is_isomorphic(&ladder, &grid).unwrap();
+    assert_preserves_edges(&ladder, &grid, &mapping);
+}
+
+#[test]
+fn predicates_restrict_mappings() {
+    let a = create_graph(3, &[(0, 1), (1, 2)]);
+    let b = create_graph(3, &[(0, 1), (1, 2)]);
+    let mapping = is_isomorphic_by(&a, &b, |v, u| v % 2 == u % 2, |_, _| true).unwrap();
+    assert_eq!(mapping[&1], 1);
+    // Only the edge 0-1 of the second graph may carry the edge 1-2 of the first one.
+    let edge_match =
+        |(v, w): (usize, usize), (u, x): (usize, usize)| (v.min(w) == 1) == (u.min(x) == 0);
+    let mapping = is_isomorphic_by(&a, &b, |_, _| true, edge_match).unwrap();
+    assert_eq!(mapping, HashMap::from([(0, 2), (1, 1), (2, 0)]));
+    assert!(is_isomorphic_by(&a, &b, |v, u| v == 1 && u == 1, |_, _| true).is_none());
+}
+
+#[test]
+fn finds_squares_in_grid() {
+    let square = create_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let grid: Grid = Grid::new(3, 4);
+    let matches = subgraph_matches(&square, &grid).collect::<Vec<_>>();
+    // Six unit squares, each matched by the eight automorphisms of a square.
+    assert_eq!(matches.len(), 6 * 8);
+    let squares = matches
+        .iter()
+        .map(|mapping| {
+            let mut cells = mapping.values().copied().collect::<Vec<_>>();
+            cells.sort();
+            cells
+        })
+        .collect::<HashSet<_>>();
+    assert_eq!(squares.len(), 6);
+    for mapping in &matches {
+        assert_preserves_edges(&square, &grid, mapping);
+    }
+}
+
+#[test]
+fn subgraph_matches_are_induced() {
+    // A path of three vertices is not an induced subgraph of a triangle.
+    let path = create_graph(3, &[(0, 1), (1, 2)]);
+    let triangle = create_graph(3, &[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(subgraph_matches(&path, &triangle).count(), 0);
+    let pair = create_graph(2, &[(0, 1)]);
+    assert_eq!(subgraph_matches(&pair, &triangle).count(), 6);
+}
+
+#[test]
+fn subgraph_matches_are_lazy() {
+    let path = create_graph(3, &[(0, 1), (1, 2)]);
+    let grid: Grid = Grid::new(20, 20);
+    let first = subgraph_matches(&path, &grid).next().unwrap();
+    assert_preserves_edges(&path, &grid, &first);
+
+    // Color the grid as a checkerboard and the path by parity.
+    let color = |id| {
+        let Coords(row, col) = grid.coords_of(id).unwrap();
+        (row + col) % 2
+    };
+    let labeled = subgraph_matches_by(&path, &grid, |v, u| v % 2 == color(u), |_, _| true);
+    let mut count = 0;
+    for mapping in labeled.take(10) {
+        assert!(mapping.iter().all(|(v, u)| v % 2 == color(*u)));
+        count += 1;
+    }
+    assert_eq!(count, 10);
+}
+
+#[test]
+fn empty_pattern_matches_once() {
+    let empty = create_graph(0, &[]);
+    let grid: Grid = Grid::new(2, 2);
+    assert_eq!(subgraph_matches(&empty, &grid).count(), 1);
+    assert!(is_isomorphic(&empty, &create_graph(0, &[])).is_some());
+}
+
+#[test]
+fn wl_hash_ignores_index_values() {
+    let a: AdjacencyList<Counter, Undirected> = {
+        let mut graph = AdjacencyList::new();
+        let ids = (0..4).map(|_| Counter::generate()).collect::<Vec<_>>();
+        for id in &ids {
+            graph.add(*id);
+        }
+        graph.link(ids[0], ids[1]);
+        graph.link(ids[1], ids[2]);
+        graph.link(ids[1], ids[3]);
+        graph
+    };
+    let b = create_graph(4, &[(3, 2), (2, 0), (2, 1)]);
+    for iterations in 0..4 {
+        let hash_a = wl_hash(&a, iterations, |_| 0);
+        let hash_b = wl_hash(&b, iterations, |_| 0);
+        assert_eq!(hash_a.hash, hash_b.hash);
+        let mut labels_a = hash_a.labels.values().collect::<Vec<_>>();
+        let mut labels_b = hash_b.labels.values().collect::<Vec<_>>();
+        labels_a.sort();
+        labels_b.sort();
+        assert_eq!(labels_a, labels_b);
+    }
+}
+
+#[test]
+fn wl_hash_distinguishes_structure() {
+    let star = create_graph(4, &[(0, 1), (0, 2), (0, 3)]);
+    let path = create_graph(4, &[(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(wl_hash(&star, 0, |_| 0).hash, wl_hash(&path, 0, |_| 0).hash);
+    assert_ne!(wl_hash(&star, 1, |_| 0).hash, wl_hash(&path, 1, |_| 0).hash);
+
+    // Refined labels separate the ends of a path from its inner vertices.
+    let labels = wl_hash(&path, 2, |_| 0).labels;
+    assert_eq!(labels[&0], labels[&3]);
+    assert_eq!(labels[&1], labels[&2]);
+    assert_ne!(labels[&0], labels[&1]);
+
+    // Initial labels are taken into account.
+    let hash = wl_hash(&path, 2, |v| (v == 0) as u64).hash;
+    assert_ne!(hash, wl_hash(&path, 2, |_| 0).hash);
+    assert_eq!(hash, wl_hash(&path, 2, |v| (v == 3) as u64).hash);
+}
+
+#[test]
+fn wl_hash_matches_grid_and_adjacency_list() {
+    let grid: Grid = Grid::new(2, 3);
+    let ladder = create_graph(6, &[(0, 1), (1, 2), (3, 4), (4, 5), (0, 3), (1, 4), (2, 5)]);
+    assert_eq!(
+        wl_hash(&grid, 3, |_| 0).hash,
+        wl_hash(&ladder, 3, |_| 0).hash
+    );
+}
+
+#[test]
+fn wl_hash_respects_direction() {
+    let a = create_directed_graph(3, &[(0, 1), (0, 2)]);
+    let b = create_directed_graph(3, &[(1, 0), (2, 0)]);
+    assert_ne!(wl_hash(&a, 1, |_| 0).hash, wl_hash(&b, 1, |_| 0).hash);
+}
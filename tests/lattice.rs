@@ -0,0 +1,103 @@
+use pretty_assertions::assert_eq;
+use std::collections::HashSet;
+
+use cinnabar::graphs::lattice::{Edge, EdgeSet};
+use cinnabar::graphs::Lattice;
+use cinnabar::prelude::*;
+use cinnabar::traversal::*;
+
+fn create_lattice() -> Lattice<Counter, 3> {
+    Lattice::new([2, 3, 4])
+}
+
+#[test]
+fn lattice_should_have_correct_order_and_size() {
+    let lattice = create_lattice();
+    assert_eq!(lattice.order(), 24);
+    assert_eq!(lattice.size(), 12 + 2 * 8 + 3 * 6);
+    assert_eq!(lattice.edges().iter().count(), lattice.size());
+    let edges = lattice.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(edges.len(), lattice.size());
+}
+
+#[test]
+fn lattice_coordinates_and_neighbors() {
+    let mut inspected = Vec::new();
+    let lattice: Lattice<Counter, 3> =
+        Lattice::with_inspector([2, 3, 4], |id, point| inspected.push((id, point)));
+    assert_eq!(inspected.len(), 24);
+    for (id, point) in inspected {
+        assert_eq!(lattice.at(point), Some(id));
+        assert_eq!(lattice.coords_of(id), Some(point));
+    }
+    assert_eq!(lattice.at([2, 0, 0]), None);
+    assert_eq!(lattice.neighbors_of([0, 0, 0]).count(), 3);
+    assert_eq!(lattice.neighbors_of([1, 1, 1]).count(), 5);
+    assert_eq!(lattice.degree(lattice.at([0, 1, 2]).unwrap()), Some(5));
+    let a = lattice.at([0, 1, 2]).unwrap();
+    let b = lattice.at([1, 1, 2]).unwrap();
+    let c = lattice.at([1, 2, 2]).unwrap();
+    assert!(lattice.edges().contains(Edge::new(a, b)));
+    assert!(!lattice.edges().contains(Edge::new(a, c)));
+}
+
+#[test]
+fn lattice_can_be_traversed() {
+    let lattice = create_lattice();
+    let start = lattice.at([0, 0, 0]).unwrap();
+    assert_eq!(bfs(&lattice.vertices(), start).count(), 24);
+    let edge = Edge::new(start, lattice.at([1, 0, 0]).unwrap());
+    let dfs_edges = dfs(&lattice.edges(), edge).collect::<EdgeSet<_>>();
+    let all_edges = lattice.edges().iter().collect::<EdgeSet<_>>();
+    assert_eq!(dfs_edges, all_edges);
+}
+
+#[test]
+fn lattice_traversal_follows_axis_order() {
+    let lattice = create_lattice();
+    let points = |order| {
+        lattice
+            .traverse_by_axes(order)
+            .map(|id| lattice.coords_of(id).unwrap())
+            .collect::<Vec<_>>()
+    };
+    let row_major = points([0, 1, 2]);
+    assert_eq!(&row_major[..3], &[[0, 0, 0], [0, 0, 1], [0, 0, 2]]);
+    let column_major = points([2, 1, 0]);
+    assert_eq!(&column_major[..3], &[[0, 0, 0], [1, 0, 0], [0, 1, 0]]);
+    let mixed = points([1, 0, 2]);
+    assert_eq!(mixed[4], [1, 0, 0]);
+    for order in [[0, 1, 2], [2, 1, 0], [1, 0, 2]] {
+        assert_eq!(points(order).into_iter().collect::<HashSet<_>>().len(), 24);
+    }
+}
+
+#[test]
+#[should_panic]
+fn lattice_traversal_requires_permutation() {
+    let lattice = create_lattice();
+    lattice.traverse_by_axes([0, 0, 1]).count();
+}
+
+#[test]
+fn lattice_can_wrap_around() {
+    let lattice = create_lattice().with_wrapping([true, true, true]);
+    // The axis of length two keeps a single edge between its layers.
+    assert_eq!(lattice.size(), 12 + 2 * 12 + 3 * 8);
+    assert_eq!(
+        lattice.edges().iter().collect::<EdgeSet<_>>().len(),
+        lattice.size()
+    );
+    assert!(lattice
+        .vertices()
+        .iter()
+        .all(|id| lattice.degree(id) == Some(5)));
+    let a = lattice.at([0, 0, 0]).unwrap();
+    let b = lattice.at([0, 0, 3]).unwrap();
+    assert!(lattice.edges().contains(Edge::new(a, b)));
+
+    let line: Lattice<Counter, 1> = Lattice::new([1]).with_wrapping([true]);
+    assert_eq!(line.size(), 0);
+    assert_eq!(line.edges().iter().count(), 0);
+    assert_eq!(line.neighbors_of([0]).count(), 0);
+}
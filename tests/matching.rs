@@ -0,0 +1,170 @@
+use pretty_assertions::assert_eq;
+use std::collections::HashSet;
+
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::matching::*;
+use cinnabar::prelude::*;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn assert_valid<G: VertexProvider<usize>>(graph: &G, matching: &Matching<usize>) {
+    let vertices = graph.vertices();
+    let mut covered = HashSet::new();
+    for edge in matching.edges() {
+        assert!(vertices.adjacent_to(edge.0).unwrap().any(|id| id == edge.1));
+        assert!(covered.insert(edge.0), "vertex is matched twice");
+        assert!(covered.insert(edge.1), "vertex is matched twice");
+    }
+    assert_eq!(covered.len(), matching.len() * 2);
+}
+
+#[test]
+fn hopcroft_karp_finds_perfect_matching() {
+    // Requests 0..3 and servers 3..6, a greedy choice of 0-3 blocks a perfect matching
+    // unless the augmenting path is found.
+    let graph = create_graph(6, &[(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)]);
+    let matching = hopcroft_karp(&graph, 0..3);
+    assert_valid(&graph, &matching);
+    assert_eq!(matching.len(), 3);
+    assert_eq!(matching.mate_of(1), Some(3));
+}
+
+#[test]
+fn hopcroft_karp_finds_maximum_matching() {
+    let graph = create_graph(7, &[(0, 4), (1, 4), (2, 4), (2, 5), (3, 5), (3, 6)]);
+    let matching = hopcroft_karp(&graph, 0..4);
+    assert_valid(&graph, &matching);
+    assert_eq!(matching.len(), 3);
+}
+
+#[test]
+fn hopcroft_karp_ignores_isolated_vertices() {
+    let graph = create_graph(4, &[(0, 2)]);
+    let matching = hopcroft_karp(&graph, [0, 1, 10]);
+    assert_eq!(matching.len(), 1);
+    assert!(!matching.is_matched(1));
+    assert!(!matching.is_matched(10));
+}
+
+#[test]
+fn hopcroft_karp_handles_long_augmenting_paths() {
+    // A long path, where greedy choices made from its far end leave a single augmenting path
+    // running through the whole graph.
+    let count = 200_000;
+    let edges = (1..count).map(|v| (v - 1, v)).collect::<Vec<_>>();
+    let graph = create_graph(count, &edges);
+    let matching = hopcroft_karp(&graph, (0..count).step_by(2).rev());
+    assert_eq!(matching.len(), count / 2);
+}
+
+#[test]
+fn vertex_cover_has_matching_size() {
+    let edges = [(0, 4), (1, 4), (2, 4), (2, 5), (3, 5), (3, 6)];
+    let graph = create_graph(7, &edges);
+    let cover = minimum_vertex_cover(&graph, 0..4);
+    assert_eq!(cover.len(), hopcroft_karp(&graph, 0..4).len());
+    for (a, b) in edges {
+        assert!(cover.contains(&a) || cover.contains(&b));
+    }
+}
+
+#[test]
+fn maximal_matching_cannot_be_extended() {
+    let edges = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 3)];
+    let graph = create_graph(6, &edges);
+    let matching = maximal_matching(&graph);
+    assert_valid(&graph, &matching);
+    for (a, b) in edges {
+        assert!(matching.is_matched(a) || matching.is_matched(b));
+    }
+}
+
+#[test]
+fn blossom_matches_odd_cycles() {
+    // A 5-cycle with a pendant vertex at 0 requires contracting the cycle to match all vertices.
+    let graph = create_graph(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 5)]);
+    let matching = maximum_matching(&graph);
+    assert_valid(&graph, &matching);
+    assert_eq!(matching.len(), 3);
+}
+
+#[test]
+fn blossom_finds_perfect_matching_in_petersen_graph() {
+    let outer = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)];
+    let spokes = [(0, 5), (1, 6), (2, 7), (3, 8), (4, 9)];
+    let inner = [(5, 7), (7, 9), (9, 6), (6, 8), (8, 5)];
+    let edges = [outer, spokes, inner].concat();
+    let graph = create_graph(10, &edges);
+    let matching = maximum_matching(&graph);
+    assert_valid(&graph, &matching);
+    assert_eq!(matching.len(), 5);
+}
+
+#[test]
+fn blossom_works_on_grids() {
+    let grid: Grid = Grid::new(3, 3);
+    let matching = maximum_matching(&grid);
+    assert_eq!(matching.len(), 4);
+    let grid: Grid = Grid::new(3, 4);
+    let matching = maximum_matching(&grid);
+    assert_eq!(matching.len(), 6);
+}
+
+#[test]
+fn hungarian_finds_minimum_cost_assignment() {
+    let costs = [[4.0, 1.0, 3.0], [2.0, 0.0, 5.0], [3.0, 2.0, 2.0]];
+    let assignment = hungarian(0..3, 0..3, |worker, job| costs[worker][job]);
+    let mut pairs = assignment.pairs.clone();
+    pairs.sort_unstable();
+    assert_eq!(pairs, vec![(0, 1), (1, 0), (2, 2)]);
+    assert_eq!(assignment.cost, 5.0);
+}
+
+#[test]
+fn hungarian_handles_more_workers_than_jobs() {
+    let costs = [[7.0, 3.0], [2.0, 8.0], [1.0, 9.0], [5.0, 4.0]];
+    let assignment = hungarian(0..4, 0..2, |worker, job| costs[worker][job]);
+    let mut pairs = assignment.pairs.clone();
+    pairs.sort_unstable();
+    assert_eq!(pairs, vec![(0, 1), (2, 0)]);
+    assert_eq!(assignment.cost, 4.0);
+}
+
+#[test]
+fn hungarian_handles_more_jobs_than_workers() {
+    let costs = [[7.0, 3.0, 1.0, 6.0], [2.0, 8.0, 1.5, 4.0]];
+    let assignment = hungarian(0..2, 0..4, |worker, job| costs[worker][job]);
+    let mut pairs = assignment.pairs.clone();
+    pairs.sort_unstable();
+    assert_eq!(pairs, vec![(0, 2), (1, 0)]);
+    assert_eq!(assignment.cost, 3.0);
+}
+
+#[test]
+fn hungarian_handles_empty_sets() {
+    let assignment = hungarian(0..0, 0..3, |_, _| 1.0);
+    assert!(assignment.pairs.is_empty());
+    assert_eq!(assignment.cost, 0.0);
+}
+
+#[test]
+#[should_panic]
+fn hungarian_rejects_infinite_costs() {
+    let costs = [[1.0, f64::INFINITY], [f64::INFINITY, f64::INFINITY]];
+    hungarian(0..2, 0..2, |worker, job| costs[worker][job]);
+}
+
+#[test]
+#[should_panic]
+fn hungarian_rejects_nan_costs() {
+    hungarian(0..2, 0..2, |_, _| f64::NAN);
+}
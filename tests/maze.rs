@@ -0,0 +1,106 @@
+use pretty_assertions::assert_eq;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use cinnabar::graphs::grid::maze::*;
+use cinnabar::graphs::grid::{Edge, EdgeSet, Neighborhood, Wrapping};
+use cinnabar::graphs::Grid;
+use cinnabar::prelude::*;
+use cinnabar::traversal::*;
+
+type Generator = fn(&Grid, &mut StdRng) -> EdgeSet<Counter>;
+
+const GENERATORS: [Generator; 5] = [recursive_backtracker, kruskal, prim, wilson, eller];
+
+const ROWS: usize = 8;
+const COLS: usize = 11;
+
+/// Check that the walled maze is a tree spanning the whole grid.
+fn assert_spanning_tree(grid: Grid, passages: &EdgeSet<Counter>) {
+    let order = grid.order();
+    assert_eq!(passages.len(), order - 1);
+    assert!(passages.iter().all(|edge| grid.edges().contains(*edge)));
+    let maze = walled(grid, passages);
+    assert_eq!(maze.size(), order - 1);
+    assert_eq!(maze.edges().iter().collect::<EdgeSet<_>>(), *passages);
+    let start = maze.at(0, 0).unwrap();
+    assert_eq!(bfs(&maze.vertices(), start).count(), order);
+}
+
+#[test]
+fn mazes_are_spanning_trees() {
+    for (k, generate) in GENERATORS.iter().enumerate() {
+        let grid: Grid = Grid::new(ROWS, COLS);
+        let passages = generate(&grid, &mut StdRng::seed_from_u64(k as u64));
+        assert_spanning_tree(grid, &passages);
+    }
+}
+
+#[test]
+fn mazes_follow_grid_topology() {
+    // Eller's algorithm only uses horizontal and vertical links, so it is checked separately.
+    for generate in &GENERATORS[..4] {
+        let grid: Grid = Grid::new(ROWS, COLS)
+            .with_neighborhood(Neighborhood::Moore)
+            .with_wrapping(Wrapping::Both);
+        let passages = generate(&grid, &mut StdRng::seed_from_u64(3));
+        assert_spanning_tree(grid, &passages);
+    }
+    let grid: Grid = Grid::new(ROWS, COLS).with_wrapping(Wrapping::Horizontal);
+    let passages = eller(&grid, &mut StdRng::seed_from_u64(3));
+    assert_spanning_tree(grid, &passages);
+}
+
+#[test]
+fn mazes_are_reproducible() {
+    for generate in GENERATORS {
+        let grid: Grid = Grid::new(ROWS, COLS);
+        let first = generate(&grid, &mut StdRng::seed_from_u64(42));
+        let second = generate(&grid, &mut StdRng::seed_from_u64(42));
+        assert_eq!(first, second);
+
+        // Grids of the same shape get the same maze, regardless of their vertex indices.
+        let other: Grid = Grid::new(ROWS, COLS);
+        let third = generate(&other, &mut StdRng::seed_from_u64(42));
+        let same = |row, col, dy, dx| {
+            let a = Edge::new(
+                grid.at(row, col).unwrap(),
+                grid.at(row + dy, col + dx).unwrap(),
+            );
+            let b = Edge::new(
+                other.at(row, col).unwrap(),
+                other.at(row + dy, col + dx).unwrap(),
+            );
+            first.contains(&a) == third.contains(&b)
+        };
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                assert!(row + 1 == ROWS || same(row, col, 1, 0));
+                assert!(col + 1 == COLS || same(row, col, 0, 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn mazes_avoid_obstacles() {
+    for (k, generate) in GENERATORS.iter().enumerate() {
+        // A column of removed cells splits the grid in two parts.
+        let mut grid: Grid = Grid::new(ROWS, COLS);
+        for row in 0..ROWS {
+            grid.remove(grid.at(row, 4).unwrap());
+        }
+        let left = grid.at(0, 0).unwrap();
+        let right = grid.at(0, 5).unwrap();
+        let wall = Edge::new(grid.at(2, 6).unwrap(), grid.at(2, 7).unwrap());
+        grid.unlink(grid.at(2, 6).unwrap(), grid.at(2, 7).unwrap());
+
+        let passages = generate(&grid, &mut StdRng::seed_from_u64(k as u64));
+        assert!(!passages.contains(&wall));
+        let order = grid.order();
+        let maze = walled(grid, &passages);
+        let parts = [left, right].map(|start| bfs(&maze.vertices(), start).count());
+        assert_eq!(parts, [4 * ROWS, 6 * ROWS]);
+        assert_eq!(passages.len(), order - 2);
+    }
+}
@@ -0,0 +1,126 @@
+use pretty_assertions::assert_eq;
+
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::metrics::*;
+use cinnabar::prelude::*;
+use cinnabar::utils::unweighted;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn create_complete(vertices: usize) -> AdjacencyList<usize, Undirected> {
+    let edges = (0..vertices)
+        .flat_map(|a| (a + 1..vertices).map(move |b| (a, b)))
+        .collect::<Vec<_>>();
+    create_graph(vertices, &edges)
+}
+
+#[test]
+fn triangles_are_counted_per_vertex() {
+    // Two triangles sharing the edge 1-2, and a pendant vertex 4.
+    let graph = create_graph(5, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    let counts = triangles::count(&graph);
+    assert_eq!(counts[&0], 1);
+    assert_eq!(counts[&1], 2);
+    assert_eq!(counts[&2], 2);
+    assert_eq!(counts[&3], 1);
+    assert_eq!(counts[&4], 0);
+    assert_eq!(triangles::total(&graph), 2);
+}
+
+#[test]
+fn complete_graph_is_fully_clustered() {
+    let graph = create_complete(6);
+    assert_eq!(triangles::total(&graph), 20);
+    assert!(triangles::count(&graph).values().all(|count| *count == 10));
+    assert!(triangles::clustering(&graph).values().all(|c| *c == 1.0));
+    assert_eq!(triangles::average_clustering(&graph), 1.0);
+    assert_eq!(triangles::transitivity(&graph), 1.0);
+}
+
+#[test]
+fn grids_have_no_triangles() {
+    let grid: Grid = Grid::new(10, 10);
+    assert_eq!(triangles::total(&grid), 0);
+    assert_eq!(triangles::transitivity(&grid), 0.0);
+}
+
+#[test]
+fn clustering_coefficients() {
+    // A triangle 0-1-2 with a pendant vertex 3 attached to 0.
+    let graph = create_graph(4, &[(0, 1), (1, 2), (2, 0), (0, 3)]);
+    let clustering = triangles::clustering(&graph);
+    assert_eq!(clustering[&0], 1.0 / 3.0);
+    assert_eq!(clustering[&1], 1.0);
+    assert_eq!(clustering[&3], 0.0);
+    let average = (1.0 / 3.0 + 1.0 + 1.0 + 0.0) / 4.0;
+    assert!((triangles::average_clustering(&graph) - average).abs() < 1e-12);
+    // 3 closed triples out of 3 + 1 + 1 connected ones.
+    assert_eq!(triangles::transitivity(&graph), 3.0 / 5.0);
+}
+
+#[test]
+fn grid_distance_metrics_have_closed_forms() {
+    // In a grid, the eccentricity of a cell is the Manhattan distance to the farthest corner.
+    let grid: Grid = Grid::new(4, 7);
+    let eccentricities = eccentricity(&grid, unweighted());
+    for row in 0..4 {
+        for col in 0..7 {
+            let id = grid.at(row, col).unwrap();
+            let expected = row.max(3 - row) + col.max(6 - col);
+            assert_eq!(eccentricities[&id], expected as f64);
+        }
+    }
+    assert_eq!(diameter(&grid, unweighted()), 9.0);
+    assert_eq!(radius(&grid, unweighted()), 5.0);
+    let mut center = center(&grid, unweighted());
+    center.sort_by_key(|id| grid.coords_of(*id).map(|c| (c.0, c.1)));
+    assert_eq!(center, vec![grid.at(1, 3).unwrap(), grid.at(2, 3).unwrap()]);
+    assert_eq!(periphery(&grid, unweighted()).len(), 4);
+    assert_eq!(approximate_diameter(&grid), 9.0);
+    assert_eq!(girth(&grid), Some(4));
+}
+
+#[test]
+fn weighted_distance_metrics() {
+    // A path 0-1-2 where the edge 1-2 is three times longer.
+    let graph = create_graph(3, &[(0, 1), (1, 2)]);
+    let cost = |a: usize, b: usize| if a + b == 3 { 3.0 } else { 1.0 };
+    let eccentricities = eccentricity(&graph, Some(cost));
+    assert_eq!(eccentricities[&0], 4.0);
+    assert_eq!(eccentricities[&1], 3.0);
+    assert_eq!(eccentricities[&2], 4.0);
+    assert_eq!(diameter(&graph, Some(cost)), 4.0);
+    assert_eq!(radius(&graph, Some(cost)), 3.0);
+    assert_eq!(center(&graph, Some(cost)), vec![1]);
+    assert_eq!(periphery(&graph, Some(cost)).len(), 2);
+}
+
+#[test]
+fn disconnected_graphs_have_infinite_diameter() {
+    let graph = create_graph(3, &[(0, 1)]);
+    assert_eq!(diameter(&graph, unweighted()), f64::INFINITY);
+    assert_eq!(eccentricity(&graph, unweighted())[&2], f64::INFINITY);
+    assert_eq!(girth(&graph), None);
+    let empty = create_graph(0, &[]);
+    assert_eq!(diameter(&empty, unweighted()), 0.0);
+    assert_eq!(radius(&empty, unweighted()), 0.0);
+    assert_eq!(approximate_diameter(&empty), 0.0);
+}
+
+#[test]
+fn girth_is_the_shortest_cycle() {
+    let graph = create_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    assert_eq!(girth(&graph), Some(5));
+    let graph = create_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (1, 3)]);
+    assert_eq!(girth(&graph), Some(3));
+    assert_eq!(girth(&create_complete(4)), Some(3));
+}
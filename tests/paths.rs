@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use cinnabar::graphs::{AdjacencyList, Grid};
+use cinnabar::paths::*;
+use cinnabar::prelude::*;
+use cinnabar::utils::UnorderedPair;
+
+fn create_graph(vertices: usize, edges: &[(usize, usize)]) -> AdjacencyList<usize, Undirected> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+fn create_directed_graph(
+    vertices: usize,
+    edges: &[(usize, usize)],
+) -> AdjacencyList<usize, Directed> {
+    let mut graph = AdjacencyList::new();
+    for id in 0..vertices {
+        graph.add(id);
+    }
+    for (a, b) in edges {
+        graph.link(*a, *b);
+    }
+    graph
+}
+
+/// Check that consecutive steps of a trail share ends.
+fn assert_trail(trail: &[(usize, usize)]) {
+    for steps in trail.windows(2) {
+        assert_eq!(steps[0].1, steps[1].0);
+    }
+}
+
+#[test]
+fn undirected_circuit_covers_every_edge() {
+    // Two triangles sharing the vertex 2.
+    let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)];
+    let graph = create_graph(5, &edges);
+    let circuit = eulerian_circuit(&graph).unwrap();
+    let steps = circuit.iter().map(|e| (e.0, e.1)).collect::<Vec<_>>();
+    assert_eq!(steps.len(), edges.len());
+    assert_trail(&steps);
+    assert_eq!(steps[0].0, steps[steps.len() - 1].1);
+    let covered = circuit.into_iter().collect::<HashSet<_>>();
+    for (a, b) in edges {
+        assert!(covered.iter().any(|e| *e == UnorderedPair(a, b)));
+    }
+}
+
+#[test]
+fn undirected_path_connects_odd_vertices() {
+    let graph = create_graph(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+    match eulerian_circuit(&graph) {
+        Err(EulerianError::OddDegree(mut odd)) => {
+            odd.sort();
+            assert_eq!(odd, vec![2, 3]);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    let steps = eulerian_path(&graph)
+        .unwrap()
+        .into_iter()
+        .map(|e| (e.0, e.1))
+        .collect::<Vec<_>>();
+    assert_eq!(steps.len(), 4);
+    assert_trail(&steps);
+    let mut ends = [steps[0].0, steps[3].1];
+    ends.sort();
+    assert_eq!(ends, [2, 3]);
+}
+
+#[test]
+fn directed_path_follows_direction() {
+    let graph = create_directed_graph(4, &[(0, 1), (1, 2), (2, 0), (0, 3)]);
+    match eulerian_circuit(&graph) {
+        Err(EulerianError::Imbalance(mut imbalance)) => {
+            imbalance.sort();
+            assert_eq!(imbalance, vec![(0, 1), (3, -1)]);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    let path = eulerian_path(&graph).unwrap();
+    assert_eq!(path, vec![(0, 1), (1, 2), (2, 0), (0, 3)]);
+
+    let graph = create_directed_graph(3, &[(0, 1), (0, 2)]);
+    assert!(matches!(
+        eulerian_path(&graph),
+        Err(EulerianError::Imbalance(imbalance)) if imbalance.len() == 3
+    ));
+}
+
+#[test]
+fn directed_circuit_covers_every_edge() {
+    let edges = [(0, 1), (1, 0), (1, 2), (2, 1), (2, 0), (0, 2)];
+    let graph = create_directed_graph(3, &edges);
+    let circuit = eulerian_circuit(&graph).unwrap();
+    assert_eq!(circuit.len(), edges.len());
+    assert_trail(&circuit);
+    assert_eq!(circuit.iter().collect::<HashSet<_>>().len(), edges.len());
+}
+
+#[test]
+fn disconnected_edges_are_reported() {
+    let graph = create_graph(7, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    match eulerian_circuit(&graph) {
+        Err(EulerianError::Disconnected(representatives)) => {
+            assert_eq!(representatives.len(), 2);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    // Isolated vertices do not matter.
+    let graph = create_graph(5, &[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(eulerian_circuit(&graph).unwrap().len(), 3);
+    assert_eq!(eulerian_circuit(&create_graph(2, &[])).unwrap(), vec![]);
+}
+
+#[test]
+fn grid_has_eulerian_path_only_when_small() {
+    // Only the two ends of a single row have odd degrees.
+    let row: Grid = Grid::new(1, 4);
+    assert_eq!(eulerian_path(&row).unwrap().len(), 3);
+    // A square has a circuit, while larger grids have odd border cells.
+    let square: Grid = Grid::new(2, 2);
+    assert_eq!(eulerian_circuit(&square).unwrap().len(), 4);
+    let grid: Grid = Grid::new(3, 4);
+    match eulerian_path(&grid) {
+        Err(EulerianError::OddDegree(odd)) => assert_eq!(odd.len(), 6),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+fn assert_hamiltonian<I: Index, G: VertexProvider<I>>(graph: &G, path: &[I]) {
+    let vertices = graph.vertices();
+    assert_eq!(path.len(), graph.order());
+    assert_eq!(path.iter().collect::<HashSet<_>>().len(), path.len());
+    for step in path.windows(2) {
+        assert!(vertices.adjacent_to(step[0]).unwrap().any(|v| v == step[1]));
+    }
+}
+
+#[test]
+fn hamiltonian_path_in_grid() {
+    let grid: Grid = Grid::new(5, 6);
+    match hamiltonian_path(&grid, 10_000) {
+        HamiltonianSearch::Found(path) => assert_hamiltonian(&grid, &path),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn hamiltonian_path_respects_direction() {
+    let graph = create_directed_graph(4, &[(3, 2), (2, 1), (1, 0), (0, 3)]);
+    match hamiltonian_path(&graph, 100) {
+        HamiltonianSearch::Found(path) => assert_hamiltonian(&graph, &path),
+        result => panic!("unexpected result {:?}", result),
+    }
+    let graph = create_directed_graph(3, &[(0, 1), (0, 2)]);
+    assert_eq!(hamiltonian_path(&graph, 100), HamiltonianSearch::Absent);
+}
+
+#[test]
+fn hamiltonian_path_absence_and_budget() {
+    // A star with three leaves has no Hamiltonian path.
+    let star = create_graph(4, &[(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(hamiltonian_path(&star, 100), HamiltonianSearch::Absent);
+    // Two triangles joined by two disjoint paths through a hub need a full search.
+    let graph = create_graph(
+        7,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (6, 0),
+            (6, 3),
+            (6, 1),
+            (6, 4),
+        ],
+    );
+    assert_eq!(hamiltonian_path(&graph, 0), HamiltonianSearch::OutOfBudget);
+    match hamiltonian_path(&graph, 1000) {
+        HamiltonianSearch::Found(path) => assert_hamiltonian(&graph, &path),
+        result => panic!("unexpected result {:?}", result),
+    }
+    assert_eq!(
+        hamiltonian_path(&create_graph(0, &[]), 0),
+        HamiltonianSearch::Found(vec![])
+    );
+}
+
+/// Points on a plane with Euclidean distances.
+const POINTS: [(f64, f64); 8] = [
+    (0.0, 0.0),
+    (4.0, 3.0),
+    (1.0, 0.0),
+    (4.0, 0.0),
+    (0.0, 3.0),
+    (2.0, 3.0),
+    (3.0, 0.0),
+    (1.0, 3.0),
+];
+
+fn distance(a: usize, b: usize) -> f64 {
+    let (dx, dy) = (POINTS[a].0 - POINTS[b].0, POINTS[a].1 - POINTS[b].1);
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn assert_tour(tour: &tsp::Tour<usize>) {
+    assert_eq!(tour.items.len(), POINTS.len());
+    assert_eq!(
+        tour.items.iter().collect::<HashSet<_>>().len(),
+        POINTS.len()
+    );
+    let cost = tsp::Tour::new(tour.items.clone(), distance).cost;
+    assert!((cost - tour.cost).abs() < 1e-9);
+}
+
+#[test]
+fn tsp_heuristics_produce_tours() {
+    // The optimal tour is the boundary of the 4x3 rectangle.
+    let optimum = 14.0;
+    let nearest = tsp::nearest_neighbor(0..POINTS.len(), distance);
+    assert_tour(&nearest);
+    let doubled = tsp::mst_doubling(0..POINTS.len(), distance);
+    assert_tour(&doubled);
+    assert!(doubled.cost <= 2.0 * optimum + 1e-9);
+    for tour in [nearest, doubled] {
+        let improved = tsp::two_opt(tour.clone(), distance);
+        assert_tour(&improved);
+        assert!(improved.cost <= tour.cost + 1e-9);
+    }
+    let improved = tsp::two_opt(
+        tsp::Tour::new(vec![0, 1, 2, 3, 4, 5, 6, 7], distance),
+        distance,
+    );
+    assert!((improved.cost - optimum).abs() < 1e-9);
+}
+
+#[test]
+fn held_karp_finds_optimal_tour() {
+    let tour = tsp::held_karp(0..POINTS.len(), distance).unwrap();
+    assert_tour(&tour);
+    assert!((tour.cost - 14.0).abs() < 1e-9);
+
+    // Asymmetric costs: going up the ring is cheap, going down is expensive.
+    let cost = |a: usize, b: usize| if (a + 1) % 5 == b { 1.0 } else { 10.0 };
+    let tour = tsp::held_karp(0..5, cost).unwrap();
+    assert_eq!(tour.items, vec![0, 1, 2, 3, 4]);
+    assert_eq!(tour.cost, 5.0);
+
+    assert!(tsp::held_karp(0..tsp::HELD_KARP_LIMIT + 1, |_, _| 1.0).is_none());
+    assert_eq!(tsp::held_karp(0..1, |_, _| 1.0).unwrap().cost, 0.0);
+}